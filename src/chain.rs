@@ -0,0 +1,327 @@
+use crate::collate::{Collate, CollateError, CollateIssue, CollateOptions, CollateResult};
+use crate::grid::{distance, Grid};
+use crate::numeric::Numeric;
+use geo_types::{Coord, CoordNum, Line, LineString, MultiLineString, MultiPolygon};
+use std::fmt;
+
+/// Closed rings built by joining pieces end to end.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chained<T>
+where
+    T: CoordNum,
+{
+    /// The closed rings, in the order of their first piece.
+    pub rings: MultiLineString<T>,
+    /// For each ring, the input indices of the pieces it was built from, in
+    /// the order they were joined. The first is always the lowest.
+    pub sources: Vec<Vec<usize>>,
+    /// Input indices of the pieces that could not be joined into a closed
+    /// ring, in input order.
+    pub unchained: Vec<usize>,
+}
+
+/// Join `segments` end to end into closed rings. Segments are reversed as
+/// needed, and endpoints at most `tolerance` apart are joined; a `tolerance`
+/// of zero only joins endpoints that are exactly equal. Where several
+/// segments could continue a ring, the one with the nearest endpoint is
+/// taken, unless that leads to a dead end, in which case the next nearest is
+/// tried.
+pub fn chain_segments<T>(segments: &[Line<T>], tolerance: f64) -> Chained<T>
+where
+    T: CoordNum + Numeric,
+{
+    let pieces: Vec<[Coord<T>; 2]> = segments.iter().map(|s| [s.start, s.end]).collect();
+    chain_pieces(pieces.iter().map(|p| &p[..]).collect(), tolerance)
+}
+
+/// Join open `fragments`, such as broken contours from a noisy mesh or a
+/// DXF file, end to end into closed rings that can then be collated.
+/// Fragments are reversed as needed, and endpoints at most `tolerance` apart
+/// are joined, nearest first, backing up past dead ends. A fragment whose
+/// own ends are within `tolerance` is closed by itself. Fragments that cannot
+/// be joined into a closed ring are left out and listed in `unchained`.
+///
+/// ```
+/// use geo_collate::{chain_fragments, Collate};
+/// use geo_types::{LineString, MultiLineString};
+///
+/// let fragments = MultiLineString(vec![
+///     LineString::from(vec![(0.0, 0.0), (0.0, 4.0), (4.0, 4.0)]),
+///     LineString::from(vec![(0.0, 0.01), (4.0, 0.0), (4.0, 3.99)]),
+/// ]);
+/// let chained = chain_fragments(&fragments, 0.05);
+/// assert!(chained.unchained.is_empty());
+/// assert_eq!(chained.rings.collate().unwrap().0.len(), 1);
+/// ```
+pub fn chain_fragments<T>(fragments: &MultiLineString<T>, tolerance: f64) -> Chained<T>
+where
+    T: CoordNum + Numeric,
+{
+    chain_pieces(fragments.0.iter().map(|f| &f.0[..]).collect(), tolerance)
+}
+
+/// Index of the endpoints of every piece, on a grid of cells `tolerance`
+/// wide.
+struct EndpointGrid<'a, T>
+where
+    T: CoordNum,
+{
+    pieces: &'a [&'a [Coord<T>]],
+    tolerance: f64,
+    endpoints: Grid<(usize, bool)>,
+}
+
+impl<'a, T> EndpointGrid<'a, T>
+where
+    T: CoordNum + Numeric,
+{
+    fn new(pieces: &'a [&'a [Coord<T>]], tolerance: f64) -> Self {
+        let mut endpoints = Grid::new(tolerance);
+        for (i, piece) in pieces.iter().enumerate() {
+            if let (Some(first), Some(last)) = (piece.first(), piece.last()) {
+                endpoints.insert(*first, (i, false));
+                endpoints.insert(*last, (i, true));
+            }
+        }
+        EndpointGrid {
+            pieces,
+            tolerance,
+            endpoints,
+        }
+    }
+
+    fn distance(&self, a: Coord<T>, b: Coord<T>) -> Option<f64> {
+        if a == b {
+            return Some(0.0);
+        }
+        let distance = distance(a, b);
+        (self.tolerance > 0.0 && distance <= self.tolerance).then_some(distance)
+    }
+
+    /// The unused pieces with an endpoint within tolerance of `c`, and
+    /// whether that endpoint is its last coordinate, farthest first.
+    fn candidates(&self, c: Coord<T>, used: &[bool]) -> Vec<(usize, bool)> {
+        let mut found: Vec<(f64, usize, bool)> = self
+            .endpoints
+            .around(c)
+            .filter(|(piece, _)| !used[*piece])
+            .filter_map(|(piece, at_end)| {
+                let endpoint = if *at_end {
+                    self.pieces[*piece].last()
+                } else {
+                    self.pieces[*piece].first()
+                };
+                let distance = endpoint.and_then(|e| self.distance(c, *e))?;
+                Some((distance, *piece, *at_end))
+            })
+            .collect();
+
+        found.sort_by(|a, b| b.0.total_cmp(&a.0).then((b.1, b.2).cmp(&(a.1, a.2))));
+        found
+            .into_iter()
+            .map(|(_, piece, at_end)| (piece, at_end))
+            .collect()
+    }
+}
+
+/// A piece joined onto a ring being chained: the ring's length before it,
+/// and the other pieces that could have been joined there instead, farthest
+/// first.
+struct Step {
+    piece: usize,
+    len: usize,
+    others: Vec<(usize, bool)>,
+}
+
+/// Join `pieces` end to end into closed rings, starting each ring from the
+/// lowest unused piece and extending it with the nearest unused piece until
+/// it gets back to its start. A ring that reaches a dead end backs up and
+/// tries the next nearest piece instead, at most as many times as there are
+/// pieces, after which the pieces it holds are left unchained.
+pub(crate) fn chain_pieces<T>(pieces: Vec<&[Coord<T>]>, tolerance: f64) -> Chained<T>
+where
+    T: CoordNum + Numeric,
+{
+    let grid = EndpointGrid::new(&pieces, tolerance);
+    let mut used = vec![false; pieces.len()];
+    let mut chained = Chained {
+        rings: MultiLineString(vec![]),
+        sources: vec![],
+        unchained: vec![],
+    };
+
+    for seed in 0..pieces.len() {
+        if used[seed] {
+            continue;
+        }
+        used[seed] = true;
+        let mut coords = pieces[seed].to_vec();
+        let mut steps: Vec<Step> = Vec::new();
+        let mut backtracks = 0;
+
+        let closed = 'chain: loop {
+            let (Some(start), Some(end)) = (coords.first().copied(), coords.last().copied()) else {
+                break false;
+            };
+            if coords.len() >= 3 && grid.distance(end, start).is_some() {
+                if end != start {
+                    coords.push(start);
+                }
+                break true;
+            }
+
+            let mut others = grid.candidates(end, &used);
+            let (next, reversed) = loop {
+                if let Some(candidate) = others.pop() {
+                    break candidate;
+                }
+                // A dead end: take back the last piece and try the next
+                // nearest in its place.
+                if backtracks == pieces.len() {
+                    break 'chain false;
+                }
+                let Some(step) = steps.pop() else {
+                    break 'chain false;
+                };
+                backtracks += 1;
+                used[step.piece] = false;
+                coords.truncate(step.len);
+                others = step.others;
+            };
+            used[next] = true;
+            steps.push(Step {
+                piece: next,
+                len: coords.len(),
+                others,
+            });
+
+            let end = coords[coords.len() - 1];
+            let mut piece = pieces[next].to_vec();
+            if reversed {
+                piece.reverse();
+            }
+            let skip = usize::from(piece.first() == Some(&end));
+            coords.extend(piece.into_iter().skip(skip));
+        };
+
+        let sources = std::iter::once(seed).chain(steps.iter().map(|step| step.piece));
+        if closed {
+            chained.rings.0.push(LineString(coords));
+            chained.sources.push(sources.collect());
+        } else {
+            chained.unchained.extend(sources);
+        }
+    }
+
+    chained.unchained.sort_unstable();
+    chained
+}
+
+impl<T> Chained<T>
+where
+    T: CoordNum,
+{
+    /// Rewrite the ring indices in `error`, which refer to `rings`, as the
+    /// index of each ring's first piece.
+    fn to_pieces(&self, error: CollateError<T>) -> CollateError<T> {
+        error.map_rings(|ring| self.sources[ring][0])
+    }
+}
+
+/// Collate an unordered set of segments, such as one layer of a sliced mesh.
+///
+/// The segments are first joined into closed rings with `chain_segments`,
+/// using `CollateOptions::join_tolerance`. Ring indices in errors and issues
+/// are the index of the lowest segment in each ring.
+impl<T> Collate<T> for &[Line<T>]
+where
+    T: CoordNum + Numeric + fmt::Debug,
+{
+    fn collate_with(&self, options: &CollateOptions) -> CollateResult<MultiPolygon<T>, T> {
+        let chained = chain_segments(self, options.join_tolerance);
+        if !chained.unchained.is_empty() {
+            return Err(CollateError::UnchainedSegments {
+                segments: chained.unchained,
+            });
+        }
+        chained
+            .rings
+            .clone()
+            .collate_into_with(options)
+            .map_err(|error| chained.to_pieces(error))
+    }
+
+    fn collate_into_with(self, options: &CollateOptions) -> CollateResult<MultiPolygon<T>, T> {
+        self.collate_with(options)
+    }
+
+    fn collate_by_depth_with(
+        &self,
+        options: &CollateOptions,
+    ) -> CollateResult<Vec<MultiPolygon<T>>, T> {
+        let chained = chain_segments(self, options.join_tolerance);
+        if !chained.unchained.is_empty() {
+            return Err(CollateError::UnchainedSegments {
+                segments: chained.unchained,
+            });
+        }
+        chained
+            .rings
+            .collate_by_depth_with(options)
+            .map_err(|error| chained.to_pieces(error))
+    }
+
+    fn collate_lenient_with(
+        &self,
+        options: &CollateOptions,
+    ) -> (MultiPolygon<T>, Vec<CollateIssue<T>>) {
+        let chained = chain_segments(self, options.join_tolerance);
+        let (collated, issues) = chained.rings.collate_lenient_with(options);
+
+        let mut issues: Vec<CollateIssue<T>> = issues
+            .into_iter()
+            .map(|issue| CollateIssue {
+                ring: chained.sources[issue.ring][0],
+                error: chained.to_pieces(issue.error),
+            })
+            .chain(chained.unchained.iter().map(|segment| CollateIssue {
+                ring: *segment,
+                error: CollateError::UnchainedSegments {
+                    segments: vec![*segment],
+                },
+            }))
+            .collect();
+        issues.sort_by_key(|issue| issue.ring);
+
+        (collated, issues)
+    }
+}
+
+/// Collate an unordered set of segments. See the implementation for
+/// `&[Line<T>]`.
+impl<T> Collate<T> for Vec<Line<T>>
+where
+    T: CoordNum + Numeric + fmt::Debug,
+{
+    fn collate_with(&self, options: &CollateOptions) -> CollateResult<MultiPolygon<T>, T> {
+        self.as_slice().collate_with(options)
+    }
+
+    fn collate_into_with(self, options: &CollateOptions) -> CollateResult<MultiPolygon<T>, T> {
+        self.as_slice().collate_with(options)
+    }
+
+    fn collate_by_depth_with(
+        &self,
+        options: &CollateOptions,
+    ) -> CollateResult<Vec<MultiPolygon<T>>, T> {
+        self.as_slice().collate_by_depth_with(options)
+    }
+
+    fn collate_lenient_with(
+        &self,
+        options: &CollateOptions,
+    ) -> (MultiPolygon<T>, Vec<CollateIssue<T>>) {
+        self.as_slice().collate_lenient_with(options)
+    }
+}
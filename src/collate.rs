@@ -1,424 +1,1613 @@
+use crate::fill::{fill_sources, FillRule};
 use crate::numeric::Numeric;
-use geo_types::{CoordNum, Line, LineString, MultiLineString, MultiPolygon, Polygon};
+use crate::sweep::{inside_segment, orientation, segments_cross, sweep_nesting};
+use crate::tree::{CollationTree, PolygonIndices};
+use geo_types::{Coord, CoordNum, Line, LineString, MultiLineString, MultiPolygon, Polygon};
 use std::cmp::Ordering;
-use std::collections::hash_map;
-use std::collections::BinaryHeap;
-use std::collections::HashMap;
 use std::collections::HashSet;
 use std::error;
 use std::fmt;
+use std::mem;
 
-#[derive(Debug)]
-pub enum CollateError {
-    NoValidLinesForSweep,
-    HoleWithoutOutline(f64, f64),
-    OutlineIsHole,
-    OutlineInOutline,
-    EmptyPolyStack,
-    IndexNotInMaps,
+/// Why a collation failed. Ring indices refer to the input rings; the sweep
+/// line that found the problem runs just above y = `sweep`, and `x` is where
+/// the offending ring crosses y = `sweep`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum CollateError<T> {
+    /// A ring wound as a hole is not directly inside an exterior. `neighbour`
+    /// is the ring directly around it, if any.
+    HoleWithoutOutline {
+        ring: usize,
+        neighbour: Option<usize>,
+        sweep: T,
+        x: T,
+    },
+    /// A ring wound as a hole sits directly inside `neighbour`, which is
+    /// itself a hole.
+    OutlineIsHole {
+        ring: usize,
+        neighbour: usize,
+        sweep: T,
+        x: T,
+    },
+    /// A ring wound as an exterior sits directly inside the exterior
+    /// `neighbour`.
+    OutlineInOutline {
+        ring: usize,
+        neighbour: usize,
+        sweep: T,
+        x: T,
+    },
+    /// The sweep left `rings` classified as neither exteriors nor holes.
+    /// Every ring is classified where the sweep first reaches it, from its
+    /// leftmost edge, or from its lowest vertex if it encloses nothing, so
+    /// this is reported rather than returning an incomplete collation should
+    /// that ever fail.
+    IndexNotInMaps { rings: Vec<usize> },
+    /// `ring` crosses `neighbour`, so neither can be said to be inside the
+    /// other.
+    RingsCross {
+        ring: usize,
+        neighbour: usize,
+        sweep: T,
+        x: T,
+    },
+    /// `ring` crosses itself, so what it encloses is ambiguous.
+    SelfIntersection { ring: usize, sweep: T, x: T },
+    /// `ring` has fewer than four coordinates or encloses no area, and the
+    /// options ask for degenerate rings to be rejected, or it has no edges at
+    /// all and so cannot be collated.
+    DegenerateRing { ring: usize },
+    /// Coordinate `vertex` of `ring` is NaN or infinite.
+    NonFinite { ring: usize, vertex: usize },
+    /// Coordinate `vertex` of `ring` is too large for collation to be exact.
+    /// 64 and 128 bit integer coordinates must be within ±2^40 (about
+    /// ±1.1e12).
+    OutOfRange { ring: usize, vertex: usize },
+    /// `ring` starts at `start` but ends at `end`, and the options do not
+    /// allow closing it.
+    OpenRing {
+        ring: usize,
+        start: (T, T),
+        end: (T, T),
+    },
+    /// `ring` lies inside `neighbour`, which a lenient collation left out.
+    /// Only reported in `CollateIssue`s.
+    InsideRejectedRing { ring: usize, neighbour: usize },
+    /// The input `segments` could not be joined into closed rings.
+    UnchainedSegments { segments: Vec<usize> },
+    /// `collate_quantized` was given a grid `scale` that is not positive and
+    /// finite.
+    InvalidScale { scale: f64 },
+    /// `collate_quantized` was given options with a fill rule, which it
+    /// cannot apply, as it builds its polygons from the input rings.
+    FillRuleNotSupported,
+    /// The boundary of the area a fill rule fills could not be joined into
+    /// closed rings where it runs along `ring`. The boundary always closes,
+    /// so this is reported rather than returning an incomplete fill should
+    /// that ever fail.
+    UnclosedFill { ring: usize },
 }
 
-impl error::Error for CollateError {}
+impl<T> CollateError<T> {
+    /// Every input ring involved in the error, offending rings first. For
+    /// `UnchainedSegments` these are segment indices instead.
+    pub fn rings(&self) -> Vec<usize> {
+        match self {
+            Self::IndexNotInMaps { rings } => rings.clone(),
+            Self::UnchainedSegments { segments } => segments.clone(),
+            Self::InvalidScale { .. } | Self::FillRuleNotSupported => vec![],
+            Self::HoleWithoutOutline {
+                ring, neighbour, ..
+            } => std::iter::once(*ring).chain(*neighbour).collect(),
+            Self::OutlineIsHole {
+                ring, neighbour, ..
+            }
+            | Self::OutlineInOutline {
+                ring, neighbour, ..
+            }
+            | Self::RingsCross {
+                ring, neighbour, ..
+            }
+            | Self::InsideRejectedRing { ring, neighbour } => vec![*ring, *neighbour],
+            Self::DegenerateRing { ring }
+            | Self::NonFinite { ring, .. }
+            | Self::OutOfRange { ring, .. }
+            | Self::OpenRing { ring, .. }
+            | Self::SelfIntersection { ring, .. }
+            | Self::UnclosedFill { ring } => vec![*ring],
+        }
+    }
+
+    /// The same error with every ring index replaced by `f(index)`, for
+    /// reporting against a different numbering of the rings.
+    pub fn map_rings<F>(self, f: F) -> Self
+    where
+        F: Fn(usize) -> usize,
+    {
+        let map = |rings: Vec<usize>| rings.into_iter().map(&f).collect();
+        match self {
+            Self::IndexNotInMaps { rings } => Self::IndexNotInMaps { rings: map(rings) },
+            Self::HoleWithoutOutline {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => Self::HoleWithoutOutline {
+                ring: f(ring),
+                neighbour: neighbour.map(&f),
+                sweep,
+                x,
+            },
+            Self::OutlineIsHole {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => Self::OutlineIsHole {
+                ring: f(ring),
+                neighbour: f(neighbour),
+                sweep,
+                x,
+            },
+            Self::OutlineInOutline {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => Self::OutlineInOutline {
+                ring: f(ring),
+                neighbour: f(neighbour),
+                sweep,
+                x,
+            },
+            Self::RingsCross {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => Self::RingsCross {
+                ring: f(ring),
+                neighbour: f(neighbour),
+                sweep,
+                x,
+            },
+            Self::SelfIntersection { ring, sweep, x } => Self::SelfIntersection {
+                ring: f(ring),
+                sweep,
+                x,
+            },
+            Self::DegenerateRing { ring } => Self::DegenerateRing { ring: f(ring) },
+            Self::NonFinite { ring, vertex } => Self::NonFinite {
+                ring: f(ring),
+                vertex,
+            },
+            Self::OutOfRange { ring, vertex } => Self::OutOfRange {
+                ring: f(ring),
+                vertex,
+            },
+            Self::OpenRing { ring, start, end } => Self::OpenRing {
+                ring: f(ring),
+                start,
+                end,
+            },
+            Self::InsideRejectedRing { ring, neighbour } => Self::InsideRejectedRing {
+                ring: f(ring),
+                neighbour: f(neighbour),
+            },
+            Self::UnchainedSegments { segments } => Self::UnchainedSegments { segments },
+            Self::InvalidScale { scale } => Self::InvalidScale { scale },
+            Self::FillRuleNotSupported => Self::FillRuleNotSupported,
+            Self::UnclosedFill { ring } => Self::UnclosedFill { ring: f(ring) },
+        }
+    }
 
-impl fmt::Display for CollateError {
+    /// The same error with every coordinate replaced by `f(coordinate)`, for
+    /// reporting in different units.
+    pub fn map_coords<U, F>(self, f: F) -> CollateError<U>
+    where
+        F: Fn(T) -> U,
+    {
+        match self {
+            Self::HoleWithoutOutline {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => CollateError::HoleWithoutOutline {
+                ring,
+                neighbour,
+                sweep: f(sweep),
+                x: f(x),
+            },
+            Self::OutlineIsHole {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => CollateError::OutlineIsHole {
+                ring,
+                neighbour,
+                sweep: f(sweep),
+                x: f(x),
+            },
+            Self::OutlineInOutline {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => CollateError::OutlineInOutline {
+                ring,
+                neighbour,
+                sweep: f(sweep),
+                x: f(x),
+            },
+            Self::RingsCross {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => CollateError::RingsCross {
+                ring,
+                neighbour,
+                sweep: f(sweep),
+                x: f(x),
+            },
+            Self::SelfIntersection { ring, sweep, x } => CollateError::SelfIntersection {
+                ring,
+                sweep: f(sweep),
+                x: f(x),
+            },
+            Self::OpenRing { ring, start, end } => CollateError::OpenRing {
+                ring,
+                start: (f(start.0), f(start.1)),
+                end: (f(end.0), f(end.1)),
+            },
+            Self::IndexNotInMaps { rings } => CollateError::IndexNotInMaps { rings },
+            Self::DegenerateRing { ring } => CollateError::DegenerateRing { ring },
+            Self::NonFinite { ring, vertex } => CollateError::NonFinite { ring, vertex },
+            Self::OutOfRange { ring, vertex } => CollateError::OutOfRange { ring, vertex },
+            Self::InsideRejectedRing { ring, neighbour } => {
+                CollateError::InsideRejectedRing { ring, neighbour }
+            }
+            Self::UnchainedSegments { segments } => CollateError::UnchainedSegments { segments },
+            Self::InvalidScale { scale } => CollateError::InvalidScale { scale },
+            Self::FillRuleNotSupported => CollateError::FillRuleNotSupported,
+            Self::UnclosedFill { ring } => CollateError::UnclosedFill { ring },
+        }
+    }
+}
+
+impl<T: fmt::Debug> error::Error for CollateError<T> {}
+
+impl<T: fmt::Debug> fmt::Display for CollateError<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Self::NoValidLinesForSweep => write!(f, "No valid lines for sweep"),
-            Self::HoleWithoutOutline(sweep, intersection) => write!(
+            Self::HoleWithoutOutline {
+                ring,
+                neighbour: Some(neighbour),
+                sweep,
+                x,
+            } => write!(
+                f,
+                "Hole {} without outline, inside ring {}, at sweep {:?} {:?}",
+                ring, neighbour, sweep, x
+            ),
+            Self::HoleWithoutOutline {
+                ring,
+                neighbour: None,
+                sweep,
+                x,
+            } => write!(
                 f,
-                "Hole without outline at sweep {} {}",
-                sweep, intersection
+                "Hole {} without outline at sweep {:?} {:?}",
+                ring, sweep, x
             ),
-            Self::OutlineIsHole => write!(f, "Previous detected outline is a hole"),
-            Self::OutlineInOutline => write!(f, "Outline directly inside outline"),
-            Self::EmptyPolyStack => write!(f, "Polygon stack empty when trying to pop"),
-            Self::IndexNotInMaps => write!(f, "Linestring index not in exterior or interior maps"),
+            Self::OutlineIsHole {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => write!(
+                f,
+                "Outline {} of hole {} is a hole, at sweep {:?} {:?}",
+                neighbour, ring, sweep, x
+            ),
+            Self::OutlineInOutline {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => write!(
+                f,
+                "Outline {} directly inside outline {} at sweep {:?} {:?}",
+                ring, neighbour, sweep, x
+            ),
+            Self::IndexNotInMaps { rings } => write!(
+                f,
+                "Rings {:?} were not classified as exteriors or holes",
+                rings
+            ),
+            Self::RingsCross {
+                ring,
+                neighbour,
+                sweep,
+                x,
+            } => write!(
+                f,
+                "Ring {} crosses ring {} at sweep {:?} {:?}",
+                ring, neighbour, sweep, x
+            ),
+            Self::SelfIntersection { ring, sweep, x } => write!(
+                f,
+                "Ring {} crosses itself at sweep {:?} {:?}",
+                ring, sweep, x
+            ),
+            Self::DegenerateRing { ring } => write!(f, "Ring {} is degenerate", ring),
+            Self::OpenRing { ring, start, end } => write!(
+                f,
+                "Ring {} is open, starting at {:?} but ending at {:?}",
+                ring, start, end
+            ),
+            Self::NonFinite { ring, vertex } => write!(
+                f,
+                "Ring {} has a non-finite coordinate at vertex {}",
+                ring, vertex
+            ),
+            Self::OutOfRange { ring, vertex } => write!(
+                f,
+                "Ring {} has a coordinate out of range at vertex {}",
+                ring, vertex
+            ),
+            Self::InsideRejectedRing { ring, neighbour } => {
+                write!(f, "Ring {} is inside rejected ring {}", ring, neighbour)
+            }
+            Self::UnchainedSegments { segments } => {
+                write!(f, "Segments {:?} do not join into closed rings", segments)
+            }
+            Self::InvalidScale { scale } => {
+                write!(f, "Scale {} is not positive and finite", scale)
+            }
+            Self::FillRuleNotSupported => {
+                write!(f, "Quantized collation cannot apply fill rules")
+            }
+            Self::UnclosedFill { ring } => {
+                write!(f, "Filled boundary along ring {} does not close", ring)
+            }
+        }
+    }
+}
+
+pub type CollateResult<R, T> = Result<R, CollateError<T>>;
+
+/// A ring left out of a lenient collation, and the error that got it left
+/// out.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CollateIssue<T> {
+    pub ring: usize,
+    pub error: CollateError<T>,
+}
+
+/// Something wrong with an input ring, as found by `validate_rings`. Edge
+/// `i` of a ring runs from its coordinate `i` to the next, the last edge
+/// closing the ring if its ends differ. Locations of crossings are rounded
+/// to the nearest integer for integers.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RingIssue<T> {
+    /// Coordinate `vertex` of `ring` is NaN or infinite.
+    NonFinite { ring: usize, vertex: usize },
+    /// Coordinate `vertex` of `ring` is too large for collation to be exact.
+    OutOfRange { ring: usize, vertex: usize },
+    /// `ring` starts at `start` but ends at `end`.
+    Unclosed {
+        ring: usize,
+        start: (T, T),
+        end: (T, T),
+    },
+    /// `ring` has `points` coordinates, counting the one closing it, where a
+    /// ring needs at least four.
+    TooFewPoints { ring: usize, points: usize },
+    /// `ring` encloses no area.
+    ZeroArea { ring: usize },
+    /// Coordinate `vertex` of `ring`, at `at`, repeats the one before it.
+    RepeatedVertex {
+        ring: usize,
+        vertex: usize,
+        at: (T, T),
+    },
+    /// Edges `edge` and `other_edge` of `ring` cross each other at `at`.
+    SelfIntersection {
+        ring: usize,
+        edge: usize,
+        other_edge: usize,
+        at: (T, T),
+    },
+    /// Edge `edge` of `ring` crosses edge `neighbour_edge` of `neighbour` at
+    /// `at`.
+    RingsCross {
+        ring: usize,
+        edge: usize,
+        neighbour: usize,
+        neighbour_edge: usize,
+        at: (T, T),
+    },
+    /// Edge `edge` of `ring` runs along edge `neighbour_edge` of
+    /// `neighbour`, which may be `ring` itself, from `from` to `to`.
+    EdgesOverlap {
+        ring: usize,
+        edge: usize,
+        neighbour: usize,
+        neighbour_edge: usize,
+        from: (T, T),
+        to: (T, T),
+    },
+    /// Coordinate `vertex` of `ring` touches edge `neighbour_edge` of
+    /// `neighbour` at `at` without crossing it. It lies inside the edge, or,
+    /// where `neighbour` is `ring` itself, pinches the ring at the edge's
+    /// first coordinate.
+    VertexTouches {
+        ring: usize,
+        vertex: usize,
+        neighbour: usize,
+        neighbour_edge: usize,
+        at: (T, T),
+    },
+}
+
+impl<T> RingIssue<T> {
+    /// Every input ring involved in the issue.
+    pub fn rings(&self) -> Vec<usize> {
+        match self {
+            Self::RingsCross {
+                ring, neighbour, ..
+            } => vec![*ring, *neighbour],
+            Self::EdgesOverlap {
+                ring, neighbour, ..
+            }
+            | Self::VertexTouches {
+                ring, neighbour, ..
+            } if ring != neighbour => vec![*ring, *neighbour],
+            Self::EdgesOverlap { ring, .. } | Self::VertexTouches { ring, .. } => vec![*ring],
+            Self::NonFinite { ring, .. }
+            | Self::OutOfRange { ring, .. }
+            | Self::Unclosed { ring, .. }
+            | Self::TooFewPoints { ring, .. }
+            | Self::ZeroArea { ring }
+            | Self::RepeatedVertex { ring, .. }
+            | Self::SelfIntersection { ring, .. } => vec![*ring],
         }
     }
 }
 
-pub type CollateResult<T> = Result<T, CollateError>;
+impl<T: fmt::Debug> fmt::Display for RingIssue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NonFinite { ring, vertex } => write!(
+                f,
+                "Ring {} has a non-finite coordinate at vertex {}",
+                ring, vertex
+            ),
+            Self::OutOfRange { ring, vertex } => write!(
+                f,
+                "Ring {} has a coordinate out of range at vertex {}",
+                ring, vertex
+            ),
+            Self::Unclosed { ring, start, end } => write!(
+                f,
+                "Ring {} is open, starting at {:?} but ending at {:?}",
+                ring, start, end
+            ),
+            Self::TooFewPoints { ring, points } => {
+                write!(f, "Ring {} has only {} points", ring, points)
+            }
+            Self::ZeroArea { ring } => write!(f, "Ring {} encloses no area", ring),
+            Self::RepeatedVertex { ring, vertex, at } => {
+                write!(f, "Ring {} repeats vertex {} at {:?}", ring, vertex, at)
+            }
+            Self::SelfIntersection {
+                ring,
+                edge,
+                other_edge,
+                at,
+            } => write!(
+                f,
+                "Ring {} crosses itself at {:?}, between edges {} and {}",
+                ring, at, edge, other_edge
+            ),
+            Self::RingsCross {
+                ring,
+                edge,
+                neighbour,
+                neighbour_edge,
+                at,
+            } => write!(
+                f,
+                "Ring {} crosses ring {} at {:?}, between edges {} and {}",
+                ring, neighbour, at, edge, neighbour_edge
+            ),
+            Self::EdgesOverlap {
+                ring,
+                edge,
+                neighbour,
+                neighbour_edge,
+                from,
+                to,
+            } => write!(
+                f,
+                "Edge {} of ring {} runs along edge {} of ring {} from {:?} to {:?}",
+                edge, ring, neighbour_edge, neighbour, from, to
+            ),
+            Self::VertexTouches {
+                ring,
+                vertex,
+                neighbour,
+                neighbour_edge,
+                at,
+            } => write!(
+                f,
+                "Vertex {} of ring {} touches edge {} of ring {} at {:?}",
+                vertex, ring, neighbour_edge, neighbour, at
+            ),
+        }
+    }
+}
 
 pub trait Collate<T>
 where
     T: CoordNum,
 {
-    fn collate(&self) -> CollateResult<MultiPolygon<T>>;
-    fn collate_into(self) -> CollateResult<MultiPolygon<T>>;
+    /// Collate with explicit `options`.
+    /// Non-consuming, makes copies of `LineString`s.
+    fn collate_with(&self, options: &CollateOptions) -> CollateResult<MultiPolygon<T>, T>;
+
+    /// Collate with explicit `options`.
+    /// Consuming, does not copy the original `LineString`s.
+    fn collate_into_with(self, options: &CollateOptions) -> CollateResult<MultiPolygon<T>, T>
+    where
+        Self: Sized;
+
+    /// Collate with explicit `options`, grouping the polygons by island
+    /// depth: entry 0 holds the top-level polygons, entry 1 the polygons
+    /// sitting in their holes, entry 2 those sitting in holes of entry 1, and
+    /// so on. Within each group polygons come in the order `options` asks for.
+    /// Non-consuming, makes copies of `LineString`s.
+    fn collate_by_depth_with(
+        &self,
+        options: &CollateOptions,
+    ) -> CollateResult<Vec<MultiPolygon<T>>, T>;
+
+    /// Collate with explicit `options`, leaving out any ring that cannot be
+    /// classified consistently instead of failing. Returns the polygons built
+    /// from the remaining rings, and one issue per ring left out.
+    /// Non-consuming, makes copies of `LineString`s.
+    fn collate_lenient_with(
+        &self,
+        options: &CollateOptions,
+    ) -> (MultiPolygon<T>, Vec<CollateIssue<T>>);
+
+    /// Collate an unsorted `MultiLineString` into a collated `MultiPolygon`.
+    /// Rings are classified by nesting depth, so their winding does not matter.
+    /// Non-consuming, makes copies of `LineString`s.
+    fn collate(&self) -> CollateResult<MultiPolygon<T>, T> {
+        self.collate_with(&CollateOptions::default())
+    }
+
+    /// Collate an unsorted `MultiLineString` into a collated `MultiPolygon`.
+    /// Rings are classified by nesting depth, so their winding does not matter.
+    /// Consuming, does not copy the original `LineString`s.
+    fn collate_into(self) -> CollateResult<MultiPolygon<T>, T>
+    where
+        Self: Sized,
+    {
+        self.collate_into_with(&CollateOptions::default())
+    }
+
+    /// Like `collate`, but the polygons are grouped by island depth. See
+    /// `collate_by_depth_with`.
+    fn collate_by_depth(&self) -> CollateResult<Vec<MultiPolygon<T>>, T> {
+        self.collate_by_depth_with(&CollateOptions::default())
+    }
+
+    /// Like `collate`, but leaves out any ring that cannot be classified
+    /// consistently instead of failing, and reports why each was left out.
+    fn collate_lenient(&self) -> (MultiPolygon<T>, Vec<CollateIssue<T>>) {
+        self.collate_lenient_with(&CollateOptions::default())
+    }
+
+    /// Like `collate`, but rings are classified by their winding: exteriors
+    /// must be clockwise and holes counter-clockwise. Rings wound the other
+    /// way are reported as errors.
+    fn collate_oriented(&self) -> CollateResult<MultiPolygon<T>, T> {
+        self.collate_with(&CollateOptions::new().orientation(OrientationPolicy::Enforce))
+    }
+
+    /// Like `collate_into`, but rings are classified by their winding:
+    /// exteriors must be clockwise and holes counter-clockwise. Rings wound
+    /// the other way are reported as errors.
+    fn collate_into_oriented(self) -> CollateResult<MultiPolygon<T>, T>
+    where
+        Self: Sized,
+    {
+        self.collate_into_with(&CollateOptions::new().orientation(OrientationPolicy::Enforce))
+    }
+
+    /// Like `collate`, but every output ring is reversed as needed so that
+    /// exteriors and holes have the winding `winding` asks for.
+    fn collate_with_winding(&self, winding: Winding) -> CollateResult<MultiPolygon<T>, T> {
+        self.collate_with(&CollateOptions::new().winding(winding))
+    }
+
+    /// Like `collate_into`, but every output ring is reversed as needed so
+    /// that exteriors and holes have the winding `winding` asks for.
+    fn collate_into_with_winding(self, winding: Winding) -> CollateResult<MultiPolygon<T>, T>
+    where
+        Self: Sized,
+    {
+        self.collate_into_with(&CollateOptions::new().winding(winding))
+    }
 }
 
-#[derive(PartialEq)]
-struct TiedLine<T>
+/// Collation results that refer to the input rings by index instead of
+/// copying them into polygons.
+pub trait CollateRings<T>
 where
     T: CoordNum,
 {
-    line: Line<T>,
-    index: usize,
+    /// The containment hierarchy of the rings, collated with `options`.
+    fn collation_tree_with(&self, options: &CollateOptions) -> CollateResult<CollationTree, T>;
+
+    /// The containment hierarchy of the rings, collated with the default
+    /// options.
+    fn collation_tree(&self) -> CollateResult<CollationTree, T> {
+        self.collation_tree_with(&CollateOptions::default())
+    }
+
+    /// The input indices of the exterior and holes of every polygon collated
+    /// with `options`, without copying any geometry. Polygons come in the
+    /// same order `Collate::collate_with` produces them in.
+    fn collate_indices_with(
+        &self,
+        options: &CollateOptions,
+    ) -> CollateResult<Vec<PolygonIndices>, T>;
+
+    /// The input indices of the exterior and holes of every polygon collated
+    /// with the default options, without copying any geometry.
+    fn collate_indices(&self) -> CollateResult<Vec<PolygonIndices>, T> {
+        self.collate_indices_with(&CollateOptions::default())
+    }
 }
 
-impl<T: CoordNum> TiedLine<T> {
-    pub fn miny(&self) -> T {
-        if self.line.start.y < self.line.end.y {
-            self.line.start.y
-        } else {
-            self.line.end.y
-        }
+/// Knobs for `Collate::collate_with` and `Collate::collate_into_with`.
+///
+/// Start from `CollateOptions::new()` (or `default()`) and chain the setters
+/// for the behaviour you want to change:
+///
+/// ```
+/// use geo_collate::{CollateOptions, DegenerateRings, Winding};
+///
+/// let options = CollateOptions::new()
+///     .winding(Winding::CounterClockwise)
+///     .degenerate_rings(DegenerateRings::Skip);
+/// ```
+#[derive(Debug, Clone, PartialEq, Default)]
+#[non_exhaustive]
+pub struct CollateOptions {
+    /// Whether ring winding decides exteriors and holes.
+    pub orientation: OrientationPolicy,
+    /// Winding of the output rings.
+    pub winding: Winding,
+    /// What to do with rings that cannot enclose anything.
+    pub degenerate_rings: DegenerateRings,
+    /// Order of the output polygons.
+    pub order: PolygonOrder,
+    /// What to do with rings whose last coordinate is not their first.
+    pub open_rings: OpenRings,
+    /// Largest gap between two segment endpoints that are joined when
+    /// chaining segments into rings. Zero joins only equal endpoints.
+    pub join_tolerance: f64,
+    /// Distance within which vertices, and the points where rings cross a
+    /// sweep line, are treated as coincident when classifying rings. Zero
+    /// requires them to be exactly equal. Only affects classification; the
+    /// output rings are the input rings, unsnapped.
+    pub epsilon: f64,
+    /// If set, rings may overlap and cross, and `Collate` returns the area
+    /// the rule fills instead of classifying the rings by nesting. The output
+    /// rings are then new rings bounding that area, built by `fill_rings`.
+    /// Errors about those are reported against the lowest input ring running
+    /// along any part of them.
+    /// `CollateRings` always classifies the input rings by nesting.
+    pub fill_rule: Option<FillRule>,
+}
+
+impl CollateOptions {
+    /// The default options, same as `collate` and `collate_into` use.
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    pub fn maxy(&self) -> T {
-        if self.line.start.y > self.line.end.y {
-            self.line.start.y
-        } else {
-            self.line.end.y
-        }
+    pub fn orientation(mut self, orientation: OrientationPolicy) -> Self {
+        self.orientation = orientation;
+        self
     }
 
-    pub fn minx(&self) -> T {
-        if self.line.start.x < self.line.end.x {
-            self.line.start.x
-        } else {
-            self.line.end.x
-        }
+    pub fn winding(mut self, winding: Winding) -> Self {
+        self.winding = winding;
+        self
     }
 
-    #[allow(dead_code)]
-    pub fn maxx(&self) -> T {
-        if self.line.start.x > self.line.end.x {
-            self.line.start.x
-        } else {
-            self.line.end.x
-        }
+    pub fn degenerate_rings(mut self, degenerate_rings: DegenerateRings) -> Self {
+        self.degenerate_rings = degenerate_rings;
+        self
     }
-}
 
-impl<T: CoordNum> Eq for TiedLine<T> {}
+    pub fn order(mut self, order: PolygonOrder) -> Self {
+        self.order = order;
+        self
+    }
 
-impl<T: CoordNum> Ord for TiedLine<T> {
-    fn cmp(&self, other: &Self) -> Ordering {
-        other.maxy().partial_cmp(&self.maxy()).unwrap()
+    pub fn open_rings(mut self, open_rings: OpenRings) -> Self {
+        self.open_rings = open_rings;
+        self
+    }
+
+    pub fn join_tolerance(mut self, join_tolerance: f64) -> Self {
+        self.join_tolerance = join_tolerance;
+        self
+    }
+
+    pub fn epsilon(mut self, epsilon: f64) -> Self {
+        self.epsilon = epsilon;
+        self
     }
-}
 
-impl<T: CoordNum> PartialOrd for TiedLine<T> {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        other.maxy().partial_cmp(&self.maxy())
+    pub fn fill_rule(mut self, fill_rule: FillRule) -> Self {
+        self.fill_rule = Some(fill_rule);
+        self
     }
 }
 
-#[derive(Debug, PartialEq)]
-enum UpDown {
-    Up,
-    Horizontal,
-    Down,
+/// How rings are sorted into exteriors and holes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OrientationPolicy {
+    /// By the number of rings enclosing each ring: even depth is an exterior,
+    /// odd depth is a hole. Winding is ignored.
+    #[default]
+    Ignore,
+    /// By the direction of each ring's leftmost edge: exteriors must run
+    /// upward on their left side (clockwise), holes downward
+    /// (counter-clockwise). Rings wound the other way are errors.
+    Enforce,
 }
 
-#[derive(Debug, PartialEq)]
-struct SweepIntersection<T>
-where
-    T: CoordNum,
-{
-    x: T,
-    direction: UpDown,
-    index: usize,
+/// Winding of the rings in a collated `MultiPolygon`. Holes are always wound
+/// opposite to exteriors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Winding {
+    /// Keep every ring as it was in the input.
+    #[default]
+    Preserve,
+    /// Counter-clockwise exteriors and clockwise holes, as required by
+    /// RFC 7946 (GeoJSON) and OGC simple features.
+    CounterClockwise,
+    /// Clockwise exteriors and counter-clockwise holes, as used by shapefiles
+    /// and by y-down screen coordinates.
+    Clockwise,
 }
 
-#[derive(Debug)]
-struct PolyRange<T>
-where
-    T: CoordNum,
-{
-    lower: T,
-    upper: T,
-    index: usize,
+/// Order of the polygons in a collated `MultiPolygon`. Whatever the order,
+/// the holes of each polygon are in input order, and the output is the same
+/// on every run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PolygonOrder {
+    /// By the input index of each polygon's exterior.
+    #[default]
+    InputIndex,
+    /// By the lower left corner of each exterior's bounding box, bottom to top
+    /// and then left to right.
+    BoundingBox,
+    /// By the area enclosed by each exterior, largest first.
+    Area,
+}
+
+/// What to do with open rings, meaning rings whose last coordinate is not the
+/// same as their first. Rings that are closed are collated with the closing
+/// edge added; the output polygons always have closed rings.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum OpenRings {
+    /// Fail with `CollateError::OpenRing`.
+    #[default]
+    Reject,
+    /// Close every open ring.
+    Close,
+    /// Close open rings whose ends are at most this far apart, and reject the
+    /// others.
+    CloseWithin(f64),
+}
+
+/// What to do with degenerate rings, meaning rings with fewer than four
+/// coordinates or with zero area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DegenerateRings {
+    /// Collate them like any other ring.
+    #[default]
+    Keep,
+    /// Leave them out of the sweep and the output.
+    Skip,
+    /// Fail with `CollateError::DegenerateRing`.
+    Reject,
+}
+
+/// Whether the first and last coordinates of `ls` are the same.
+fn is_closed<T: CoordNum>(ls: &LineString<T>) -> bool {
+    ls.0.first() == ls.0.last()
+}
+
+/// The edges of `ls`, including the one closing it if it is open.
+pub(crate) fn ring_lines<T: CoordNum>(ls: &LineString<T>) -> impl Iterator<Item = Line<T>> + '_ {
+    let closing = match (ls.0.last(), ls.0.first()) {
+        (Some(last), Some(first)) if last != first => Some(Line::new(*last, *first)),
+        _ => None,
+    };
+    ls.lines().chain(closing)
 }
 
-fn get_poly_ranges<T>(polys: &MultiLineString<T>) -> Vec<PolyRange<T>>
+/// Signed area of a ring, positive when it is wound counter-clockwise.
+/// Coordinates are taken relative to the first, so that rings far from the
+/// origin don't lose their area to rounding.
+pub(crate) fn signed_area<T>(ls: &LineString<T>) -> f64
 where
-    T: CoordNum + PartialOrd,
+    T: CoordNum + Numeric,
 {
-    polys
-        .0
-        .iter()
-        .enumerate()
-        .map(|(index, ls)| {
-            let miny =
-                ls.0.iter()
-                    .map(|l| l.y)
-                    .min_by(|a, b| a.partial_cmp(b).unwrap())
-                    .unwrap();
-            let maxy =
-                ls.0.iter()
-                    .map(|l| l.y)
-                    .max_by(|a, b| a.partial_cmp(b).unwrap())
-                    .unwrap();
-
-            PolyRange {
-                lower: miny,
-                upper: maxy,
-                index,
-            }
+    let Some(origin) = ls.0.first() else {
+        return 0.0;
+    };
+    let (ox, oy) = (origin.x.to_float(), origin.y.to_float());
+    ring_lines(ls)
+        .map(|line| {
+            let (x0, y0) = (line.start.x.to_float() - ox, line.start.y.to_float() - oy);
+            let (x1, y1) = (line.end.x.to_float() - ox, line.end.y.to_float() - oy);
+            x0 * y1 - x1 * y0
         })
-        .collect()
+        .sum::<f64>()
+        / 2.0
 }
 
-fn get_sweep_lines<T>(ranges: Vec<PolyRange<T>>) -> Vec<T>
+/// Reverse `ls` if needed so it has the winding `winding` asks for, given
+/// whether it ends up as a hole or an exterior.
+fn wind<T>(mut ls: LineString<T>, is_hole: bool, winding: Winding) -> LineString<T>
 where
     T: CoordNum + Numeric,
 {
-    let mut highest_low = ranges.first().unwrap().lower;
-    let mut lowest_high = ranges.first().unwrap().upper;
+    let want_ccw = match winding {
+        Winding::Preserve => return ls,
+        Winding::CounterClockwise => !is_hole,
+        Winding::Clockwise => is_hole,
+    };
+    let area = signed_area(&ls);
 
-    let mut sweeps = Vec::<T>::new();
-
-    for range in ranges.iter() {
-        if range.lower >= lowest_high {
-            sweeps.push((lowest_high - highest_low) / T::from_int(2) + highest_low);
-            highest_low = range.lower;
-            lowest_high = range.upper;
-        } else {
-            if range.lower > highest_low {
-                highest_low = range.lower;
-            }
-            if range.upper < lowest_high {
-                lowest_high = range.upper;
-            }
-        }
+    if area != 0.0 && (area > 0.0) != want_ccw {
+        ls.0.reverse();
     }
-    sweeps.push((lowest_high - highest_low) / T::from_int(2) + highest_low);
+    ls
+}
 
-    sweeps
+pub(crate) fn is_degenerate<T>(ls: &LineString<T>) -> bool
+where
+    T: CoordNum + Numeric,
+{
+    let coords = ls.0.len() + usize::from(!is_closed(ls));
+    coords < 4 || signed_area(ls) == 0.0
 }
 
-fn tie_lines_to_polys<T>(polys: &MultiLineString<T>) -> Vec<TiedLine<T>>
+/// Whether `point` lies inside `ring`, by counting the ring's edges crossed
+/// by a ray running right from `point`.
+fn ring_contains<T>(ring: &LineString<T>, point: Coord<T>) -> bool
 where
-    T: CoordNum,
+    T: CoordNum + Numeric,
 {
-    let mut lines = Vec::<TiedLine<T>>::new();
+    let (px, py) = (point.x.to_float(), point.y.to_float());
+    ring_lines(ring)
+        .filter(|line| {
+            let (x0, y0) = (line.start.x.to_float(), line.start.y.to_float());
+            let (x1, y1) = (line.end.x.to_float(), line.end.y.to_float());
+            (y0 > py) != (y1 > py) && px < x0 + (py - y0) * (x1 - x0) / (y1 - y0)
+        })
+        .count()
+        % 2
+        == 1
+}
 
-    for (index, ls) in polys.0.iter().enumerate() {
-        for line in ls.lines() {
-            lines.push(TiedLine { line, index });
-        }
+/// The error for `ls` if it is open and `policy` says not to close it.
+fn open_ring_error<T>(ring: usize, ls: &LineString<T>, policy: OpenRings) -> Option<CollateError<T>>
+where
+    T: CoordNum + Numeric,
+{
+    let (first, last) = (*ls.0.first()?, *ls.0.last()?);
+    if first == last {
+        return None;
+    }
+
+    let gap =
+        (last.x.to_float() - first.x.to_float()).hypot(last.y.to_float() - first.y.to_float());
+    match policy {
+        OpenRings::Close => None,
+        OpenRings::CloseWithin(tolerance) if gap <= tolerance => None,
+        _ => Some(CollateError::OpenRing {
+            ring,
+            start: first.x_y(),
+            end: last.x_y(),
+        }),
     }
-    lines
 }
 
-fn get_poly_hole_map<T>(
-    polys: &MultiLineString<T>,
-) -> CollateResult<(HashMap<usize, usize>, HashSet<usize>)>
+/// Check every ring of `lines` before sweeping. Returns the rings the
+/// degenerate ring policy says to skip, and one issue for each ring that
+/// cannot be collated at all: rings with a non-finite coordinate, open rings
+/// the open ring policy does not close, rings with no edges, and degenerate
+/// rings the degenerate ring policy rejects.
+fn screen_rings<T>(
+    lines: &MultiLineString<T>,
+    options: &CollateOptions,
+) -> (HashSet<usize>, Vec<CollateIssue<T>>)
 where
     T: CoordNum + Numeric,
 {
-    let mut poly_ranges = get_poly_ranges(polys);
+    let policy = options.degenerate_rings;
+    let mut skipped = HashSet::new();
+    let mut issues = Vec::new();
 
-    poly_ranges.sort_unstable_by(|a, b| a.lower.partial_cmp(&b.lower).unwrap());
+    for (ring, ls) in lines.0.iter().enumerate() {
+        let error = if let Some(vertex) =
+            ls.0.iter()
+                .position(|c| !(c.x.is_finite() && c.y.is_finite()))
+        {
+            CollateError::NonFinite { ring, vertex }
+        } else if let Some(vertex) =
+            ls.0.iter()
+                .position(|c| !(c.x.in_range() && c.y.in_range()))
+        {
+            CollateError::OutOfRange { ring, vertex }
+        } else if let Some(error) = open_ring_error(ring, ls, options.open_rings) {
+            error
+        } else if policy == DegenerateRings::Skip && is_degenerate(ls) {
+            skipped.insert(ring);
+            continue;
+        } else if ls.0.len() < 2 || (policy == DegenerateRings::Reject && is_degenerate(ls)) {
+            CollateError::DegenerateRing { ring }
+        } else {
+            continue;
+        };
+        issues.push(CollateIssue { ring, error });
+    }
 
-    let mut sweeps = get_sweep_lines(poly_ranges);
-    sweeps.sort_unstable_by(|a, b| a.partial_cmp(&b).unwrap());
+    (skipped, issues)
+}
 
-    let mut lines = tie_lines_to_polys(polys);
-    lines.sort_unstable_by(|a, b| a.miny().partial_cmp(&b.miny()).unwrap());
+/// A ring and the index of one of its edges or vertices.
+type RingPart = (usize, usize);
 
-    let mut valid_lines = BinaryHeap::new();
-    let mut cur_line_iter = lines.iter().peekable();
+/// Check every ring of `lines` for anything that could keep it from
+/// collating, or make it collate into an invalid polygon, and return every
+/// issue found, ordered by ring.
+///
+/// Rings are checked for non-finite or out of range coordinates, being
+/// unclosed, having fewer than four points, enclosing no area, and repeating
+/// a vertex. Every pair of edges is checked for crossing, within a ring or
+/// between rings. Edges that touch without crossing are reported too, where
+/// they run along each other, or where a vertex lies inside another edge or
+/// pinches its own ring; collation may reject those, and where it doesn't,
+/// the polygons may not be valid. Rings with non-finite or out of range
+/// coordinates are left out of these checks.
+///
+/// ```
+/// use geo_collate::{validate_rings, RingIssue};
+/// use geo_types::{LineString, MultiLineString};
+///
+/// let bowtie: LineString<i64> = vec![(0, 0), (3, 3), (3, 0), (0, 6), (0, 0)].into();
+/// let issues = validate_rings(&MultiLineString(vec![bowtie]));
+/// assert_eq!(
+///     issues,
+///     vec![RingIssue::SelfIntersection {
+///         ring: 0,
+///         edge: 0,
+///         other_edge: 2,
+///         at: (2, 2),
+///     }]
+/// );
+/// ```
+pub fn validate_rings<T>(lines: &MultiLineString<T>) -> Vec<RingIssue<T>>
+where
+    T: CoordNum + Numeric,
+{
+    let mut issues = Vec::new();
+    let mut edges: Vec<(usize, usize, Line<T>)> = Vec::new();
+    // Which edges of each ring have no length.
+    let mut empty: Vec<Vec<bool>> = vec![Vec::new(); lines.0.len()];
 
-    let mut hole_of = HashMap::<usize, usize>::new();
-    let mut exteriors = HashSet::<usize>::new();
+    for (ring, ls) in lines.0.iter().enumerate() {
+        if let Some(vertex) =
+            ls.0.iter()
+                .position(|c| !(c.x.is_finite() && c.y.is_finite()))
+        {
+            issues.push(RingIssue::NonFinite { ring, vertex });
+            continue;
+        }
+        if let Some(vertex) =
+            ls.0.iter()
+                .position(|c| !(c.x.in_range() && c.y.in_range()))
+        {
+            issues.push(RingIssue::OutOfRange { ring, vertex });
+            continue;
+        }
 
-    for sweep in sweeps {
-        while cur_line_iter.peek() != None {
-            let peeked = &cur_line_iter.peek().unwrap();
-            if peeked.miny() > sweep {
-                break;
+        if let (Some(first), Some(last)) = (ls.0.first(), ls.0.last()) {
+            if first != last {
+                issues.push(RingIssue::Unclosed {
+                    ring,
+                    start: first.x_y(),
+                    end: last.x_y(),
+                });
             }
-            let next = cur_line_iter.next().unwrap();
-
-            if next.maxy() >= sweep {
-                valid_lines.push(next);
+        }
+        let points = ls.0.len() + usize::from(!is_closed(ls));
+        if points < 4 {
+            issues.push(RingIssue::TooFewPoints { ring, points });
+        } else if signed_area(ls) == 0.0 {
+            issues.push(RingIssue::ZeroArea { ring });
+        }
+        for (vertex, pair) in ls.0.windows(2).enumerate() {
+            if pair[0] == pair[1] {
+                issues.push(RingIssue::RepeatedVertex {
+                    ring,
+                    vertex: vertex + 1,
+                    at: pair[1].x_y(),
+                });
             }
         }
 
-        while !valid_lines.is_empty() && valid_lines.peek().unwrap().maxy() < sweep {
-            valid_lines.pop();
-        }
+        empty[ring] = ring_lines(ls).map(|line| line.start == line.end).collect();
+        edges.extend(
+            ring_lines(ls)
+                .enumerate()
+                .filter(|(_, line)| line.start != line.end)
+                .map(|(edge, line)| (ring, edge, line)),
+        );
+    }
 
-        if valid_lines.is_empty() {
-            return Err(CollateError::NoValidLinesForSweep);
-        }
+    // Edges of one ring are adjacent if only edges with no length lie
+    // between them, one way round or the other.
+    let adjacent = |ring: usize, one: usize, two: usize| {
+        let empty = &empty[ring];
+        let reaches = |from: usize, to: usize| {
+            let mut edge = (from + 1) % empty.len();
+            while edge != to {
+                if edge == from || !empty[edge] {
+                    return false;
+                }
+                edge = (edge + 1) % empty.len();
+            }
+            true
+        };
+        reaches(one, two) || reaches(two, one)
+    };
+    // The vertex edge `edge` of `ring` starts at, or ends at if `end`.
+    let vertex =
+        |ring: usize, edge: usize, end: bool| (edge + usize::from(end)) % empty[ring].len();
 
-        let mut intersections: Vec<SweepIntersection<T>> = valid_lines
+    // Only edges whose y-ranges overlap can cross, so walk the edges up by
+    // their lowest y and compare each with those starting below its top.
+    let min_y = |line: &Line<T>| {
+        if line.start.y < line.end.y {
+            line.start.y
+        } else {
+            line.end.y
+        }
+    };
+    let max_y = |line: &Line<T>| {
+        if line.start.y < line.end.y {
+            line.end.y
+        } else {
+            line.start.y
+        }
+    };
+    edges.sort_by(|a, b| {
+        min_y(&a.2)
+            .partial_cmp(&min_y(&b.2))
+            .unwrap_or(Ordering::Equal)
+    });
+    // Each issue found between a pair of edges, keyed by ring and edge, or
+    // vertex, to sort them.
+    let mut found: Vec<(RingPart, RingPart, RingIssue<T>)> = Vec::new();
+    let mut overlaps: HashSet<(RingPart, RingPart)> = HashSet::new();
+    let mut touches: Vec<(RingPart, RingPart, Coord<T>)> = Vec::new();
+    for (i, (ring, edge, line)) in edges.iter().enumerate() {
+        let top = max_y(line);
+        for (other_ring, other_edge, other) in edges[i + 1..]
             .iter()
-            .map(|line| {
-                if line.line.start.y == line.line.end.y {
-                    SweepIntersection {
-                        x: line.minx(),
-                        direction: UpDown::Horizontal,
-                        index: line.index,
+            .take_while(|(_, _, other)| min_y(other) <= top)
+        {
+            if ring == other_ring && adjacent(*ring, *edge, *other_edge) {
+                continue;
+            }
+            let (one, two) = ((*ring, *edge), (*other_ring, *other_edge));
+            let (one, two, line, other) = if one < two {
+                (one, two, line, other)
+            } else {
+                (two, one, other, line)
+            };
+
+            if segments_cross(line.start, line.end, other.start, other.end) {
+                let at = crossing_point(line, other);
+                let issue = if one.0 == two.0 {
+                    RingIssue::SelfIntersection {
+                        ring: one.0,
+                        edge: one.1,
+                        other_edge: two.1,
+                        at,
                     }
                 } else {
-                    let lefty = if line.line.start.x < line.line.end.x {
-                        line.line.start.y
-                    } else {
-                        line.line.end.y
-                    };
-
-                    let x = line.minx() + (sweep - lefty) * line.line.dx() / line.line.dy();
-
-                    let direction = if line.line.start.y < line.line.end.y {
-                        UpDown::Up
-                    } else {
-                        UpDown::Down
-                    };
-
-                    SweepIntersection {
-                        x,
-                        direction,
-                        index: line.index,
+                    RingIssue::RingsCross {
+                        ring: one.0,
+                        edge: one.1,
+                        neighbour: two.0,
+                        neighbour_edge: two.1,
+                        at,
+                    }
+                };
+                found.push((one, two, issue));
+            } else if let Some((from, to)) = collinear_overlap(line, other) {
+                overlaps.insert((one, two));
+                overlaps.insert((two, one));
+                found.push((
+                    one,
+                    two,
+                    RingIssue::EdgesOverlap {
+                        ring: one.0,
+                        edge: one.1,
+                        neighbour: two.0,
+                        neighbour_edge: two.1,
+                        from: from.x_y(),
+                        to: to.x_y(),
+                    },
+                ));
+            } else {
+                for (one, two, line, other) in [(one, two, line, other), (two, one, other, line)] {
+                    let ring = one.0;
+                    for (end, c) in [(false, line.start), (true, line.end)] {
+                        let at = vertex(ring, one.1, end);
+                        if inside_segment(other.start, other.end, c) {
+                            touches.push(((ring, at), two, c));
+                        } else if ring == two.0 && c == other.start {
+                            // A pinch, reported as the later vertex touching
+                            // the edge leaving the earlier.
+                            let earlier = vertex(ring, two.1, false);
+                            if earlier < at {
+                                touches.push(((ring, at), (ring, earlier), c));
+                            }
+                        }
                     }
                 }
-            })
-            .collect();
-        intersections.sort_unstable_by(|a: &SweepIntersection<T>, b: &SweepIntersection<T>| {
-            a.x.partial_cmp(&b.x).unwrap()
-        });
+            }
+        }
+    }
 
-        intersections.dedup();
+    // A vertex touching an edge that one of its own edges runs along is
+    // already reported as their overlap.
+    touches.retain(|((ring, vertex), neighbour, _)| {
+        let edges = empty[*ring].len();
+        ![(vertex + edges - 1) % edges, *vertex]
+            .iter()
+            .any(|edge| overlaps.contains(&((*ring, *edge), *neighbour)))
+    });
+    touches.sort_by_key(|(one, two, _)| (*one, *two));
+    touches.dedup_by_key(|(one, two, _)| (*one, *two));
+    found.extend(touches.into_iter().map(|(one, two, c)| {
+        (
+            one,
+            two,
+            RingIssue::VertexTouches {
+                ring: one.0,
+                vertex: one.1,
+                neighbour: two.0,
+                neighbour_edge: two.1,
+                at: c.x_y(),
+            },
+        )
+    }));
+    found.sort_by_key(|(one, two, _)| (*one, *two));
 
-        let mut poly_stack = Vec::<usize>::new();
-        let mut inside = false;
+    issues.extend(found.into_iter().map(|(_, _, issue)| issue));
+    issues.sort_by_key(|issue| issue.rings()[0]);
+    issues
+}
+
+/// Where the segments `a` and `b` run along each other, from and to in the
+/// direction of `a`, if they are collinear and share more than a point.
+fn collinear_overlap<T>(a: &Line<T>, b: &Line<T>) -> Option<(Coord<T>, Coord<T>)>
+where
+    T: CoordNum + Numeric,
+{
+    if orientation(a.start, a.end, b.start) != Ordering::Equal
+        || orientation(a.start, a.end, b.end) != Ordering::Equal
+    {
+        return None;
+    }
+    // How far along `a` each point is, scaled by its length squared.
+    let along = |c: Coord<T>| {
+        (c.x.widen() - a.start.x.widen()) * (a.end.x.widen() - a.start.x.widen())
+            + (c.y.widen() - a.start.y.widen()) * (a.end.y.widen() - a.start.y.widen())
+    };
+    let cmp = |one: &Coord<T>, two: &Coord<T>| {
+        along(*one)
+            .partial_cmp(&along(*two))
+            .unwrap_or(Ordering::Equal)
+    };
+    let (b_first, b_last) = if cmp(&b.start, &b.end) == Ordering::Greater {
+        (b.end, b.start)
+    } else {
+        (b.start, b.end)
+    };
+    let from = [a.start, b_first].into_iter().max_by(cmp)?;
+    let to = [a.end, b_last].into_iter().min_by(cmp)?;
+    (cmp(&from, &to) == Ordering::Less).then_some((from, to))
+}
 
-        for intersection in intersections {
-            let last = poly_stack.last();
+/// Where the segments `a` and `b`, which cross, cross each other.
+pub(crate) fn crossing_point<T>(a: &Line<T>, b: &Line<T>) -> (T, T)
+where
+    T: CoordNum + Numeric,
+{
+    let (ax, ay) = (a.start.x.to_float(), a.start.y.to_float());
+    let (adx, ady) = (a.end.x.to_float() - ax, a.end.y.to_float() - ay);
+    let (bx, by) = (b.start.x.to_float(), b.start.y.to_float());
+    let (bdx, bdy) = (b.end.x.to_float() - bx, b.end.y.to_float() - by);
 
-            if !inside {
-                match intersection.direction {
-                    UpDown::Up => {
-                        if last == None || intersection.index != *last.unwrap() {
-                            exteriors.insert(intersection.index);
-                        }
-                        inside = true;
-                    }
-                    UpDown::Down => {
-                        return Err(CollateError::HoleWithoutOutline(
-                            sweep.as_float(),
-                            intersection.x.as_float(),
-                        ))
-                    }
-                    UpDown::Horizontal => continue,
+    let t = ((bx - ax) * bdy - (by - ay) * bdx) / (adx * bdy - ady * bdx);
+    (T::from_float(ax + t * adx), T::from_float(ay + t * ady))
+}
+
+/// The rings bounding the area `rule` fills in `lines`, the input ring each
+/// is reported as, and the options to collate them with. Rings that cannot be
+/// collated at all are left out, with one issue for each, as are those the
+/// degenerate ring policy skips.
+fn fill<T>(lines: &MultiLineString<T>, options: &CollateOptions, rule: FillRule) -> Filled<T>
+where
+    T: CoordNum + Numeric,
+{
+    let (skipped, mut issues) = screen_rings(lines, options);
+    let kept: Vec<usize> = (0..lines.0.len())
+        .filter(|ring| !skipped.contains(ring) && !issues.iter().any(|i| i.ring == *ring))
+        .collect();
+    let (rings, sources, unclosed) = fill_sources(
+        &kept.iter().map(|ring| lines.0[*ring].clone()).collect(),
+        rule,
+    );
+    issues.extend(unclosed.into_iter().map(|ring| CollateIssue {
+        ring: kept[ring],
+        error: CollateError::UnclosedFill { ring: kept[ring] },
+    }));
+    Filled {
+        rings,
+        sources: sources.into_iter().map(|ring| kept[ring]).collect(),
+        issues,
+        options: CollateOptions {
+            fill_rule: None,
+            ..options.clone()
+        },
+    }
+}
+
+/// The rings bounding the area a fill rule fills, as `fill` returns them.
+struct Filled<T>
+where
+    T: CoordNum,
+{
+    rings: MultiLineString<T>,
+    /// For each ring, the lowest input ring running along any part of it,
+    /// which errors about it are reported against.
+    sources: Vec<usize>,
+    issues: Vec<CollateIssue<T>>,
+    options: CollateOptions,
+}
+
+impl<T> Filled<T>
+where
+    T: CoordNum,
+{
+    /// Rewrite the ring indices in `error`, which refer to `rings`, as input
+    /// ring indices.
+    fn to_sources(&self, error: CollateError<T>) -> CollateError<T> {
+        error.map_rings(|ring| self.sources[ring])
+    }
+}
+
+/// Sweep `lines` and build the containment tree of its rings.
+fn build_tree<T>(
+    lines: &MultiLineString<T>,
+    options: &CollateOptions,
+) -> CollateResult<CollationTree, T>
+where
+    T: CoordNum + Numeric,
+{
+    let (skipped, issues) = screen_rings(lines, options);
+    if let Some(issue) = issues.into_iter().next() {
+        return Err(issue.error);
+    }
+    sweep_tree(lines, &skipped, options)
+}
+
+/// Build the containment tree of `lines` as best as possible, leaving out
+/// every ring that cannot be classified consistently and reporting why.
+///
+/// Whenever the sweep fails, the rings it blames are left out and the sweep
+/// runs again over the rest. Rings lying inside a left out ring are left out
+/// too, since their depth, and so whether they are holes, depends on it.
+fn build_tree_lenient<T>(
+    lines: &MultiLineString<T>,
+    options: &CollateOptions,
+) -> (CollationTree, Vec<CollateIssue<T>>)
+where
+    T: CoordNum + Numeric,
+{
+    let (mut excluded, mut issues) = screen_rings(lines, options);
+    excluded.extend(issues.iter().map(|issue| issue.ring));
+
+    loop {
+        let error = match sweep_tree(lines, &excluded, options) {
+            Ok(tree) => {
+                // Rings with non-finite coordinates enclose nothing meaningful.
+                let rejected: Vec<usize> = issues
+                    .iter()
+                    .filter(|issue| !matches!(issue.error, CollateError::NonFinite { .. }))
+                    .map(|issue| issue.ring)
+                    .collect();
+                let enclosed: Vec<(usize, usize)> = (0..lines.0.len())
+                    .filter(|ring| !excluded.contains(ring))
+                    .filter_map(|ring| {
+                        let point = *lines.0[ring].0.first()?;
+                        rejected
+                            .iter()
+                            .find(|rejected| ring_contains(&lines.0[**rejected], point))
+                            .map(|rejected| (ring, *rejected))
+                    })
+                    .collect();
+
+                if enclosed.is_empty() {
+                    issues.sort_by_key(|issue| issue.ring);
+                    return (tree, issues);
                 }
-            } else {
-                match intersection.direction {
-                    UpDown::Up => return Err(CollateError::OutlineInOutline),
-                    UpDown::Down => match poly_stack.last() {
-                        None => return Err(CollateError::EmptyPolyStack),
-                        Some(last) => {
-                            if intersection.index != *last {
-                                if hole_of.contains_key(last) {
-                                    return Err(CollateError::OutlineIsHole);
-                                } else {
-                                    hole_of.insert(intersection.index, *last);
-                                }
-                            }
-                            inside = false;
-                        }
-                    },
-                    UpDown::Horizontal => continue,
+                for (ring, neighbour) in enclosed {
+                    excluded.insert(ring);
+                    issues.push(CollateIssue {
+                        ring,
+                        error: CollateError::InsideRejectedRing { ring, neighbour },
+                    });
                 }
+                continue;
             }
+            Err(error) => error,
+        };
 
-            if last != None && intersection.index == *poly_stack.last().unwrap() {
-                poly_stack.pop();
-            } else {
-                poly_stack.push(intersection.index);
-            }
+        let mut blamed: Vec<usize> = match &error {
+            CollateError::RingsCross {
+                ring, neighbour, ..
+            } => vec![*ring, *neighbour],
+            CollateError::HoleWithoutOutline { ring, .. }
+            | CollateError::OutlineIsHole { ring, .. }
+            | CollateError::OutlineInOutline { ring, .. }
+            | CollateError::DegenerateRing { ring }
+            | CollateError::NonFinite { ring, .. }
+            | CollateError::OutOfRange { ring, .. }
+            | CollateError::OpenRing { ring, .. }
+            | CollateError::SelfIntersection { ring, .. }
+            | CollateError::InsideRejectedRing { ring, .. }
+            | CollateError::UnclosedFill { ring } => vec![*ring],
+            CollateError::IndexNotInMaps { rings } => rings.clone(),
+            CollateError::UnchainedSegments { .. }
+            | CollateError::InvalidScale { .. }
+            | CollateError::FillRuleNotSupported => vec![],
+        };
+        blamed.retain(|ring| !excluded.contains(ring));
+        if blamed.is_empty() {
+            // Nothing left to blame, so nothing can be trusted.
+            blamed = (0..lines.0.len())
+                .filter(|ring| !excluded.contains(ring))
+                .collect();
         }
+
+        for ring in blamed {
+            excluded.insert(ring);
+            issues.push(CollateIssue {
+                ring,
+                error: error.clone(),
+            });
+        }
+    }
+}
+
+/// Sweep `lines`, leaving out the rings in `skipped`, and build the
+/// containment tree of the others.
+fn sweep_tree<T>(
+    lines: &MultiLineString<T>,
+    skipped: &HashSet<usize>,
+    options: &CollateOptions,
+) -> CollateResult<CollationTree, T>
+where
+    T: CoordNum + Numeric,
+{
+    let nesting = sweep_nesting(lines, skipped, options.orientation, options.epsilon)?;
+    let tree = CollationTree::new(nesting.parents, skipped);
+
+    // Check that every ring was reached and has a parent chain leading to a
+    // root, and name any that don't.
+    let unclassified: Vec<usize> = (0..tree.len())
+        .filter(|i| !skipped.contains(i) && (!nesting.reached[*i] || tree.depth(*i).is_none()))
+        .collect();
+    if !unclassified.is_empty() {
+        return Err(CollateError::IndexNotInMaps {
+            rings: unclassified,
+        });
     }
+    Ok(tree)
+}
 
-    Ok((hole_of, exteriors))
+/// Sort `polygons` into the order `order` asks for. The sort is stable, so
+/// polygons that compare equal stay in exterior input order.
+fn order_polygons<T>(
+    lines: &MultiLineString<T>,
+    polygons: &mut [PolygonIndices],
+    order: PolygonOrder,
+) where
+    T: CoordNum + Numeric,
+{
+    match order {
+        PolygonOrder::InputIndex => {}
+        PolygonOrder::BoundingBox => {
+            let lower_left = |poly: &PolygonIndices| {
+                let coords = &lines.0[poly.exterior].0;
+                let min = |f: fn(&Coord<T>) -> T| {
+                    coords
+                        .iter()
+                        .map(|c| f(c).to_float())
+                        .min_by(f64::total_cmp)
+                        .unwrap_or(0.0)
+                };
+                (min(|c| c.y), min(|c| c.x))
+            };
+            polygons.sort_by(|a, b| {
+                let (a, b) = (lower_left(a), lower_left(b));
+                a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1))
+            });
+        }
+        PolygonOrder::Area => {
+            let area = |poly: &PolygonIndices| signed_area(&lines.0[poly.exterior]).abs();
+            polygons.sort_by(|a, b| area(b).total_cmp(&area(a)));
+        }
+    }
+}
+
+/// Build one polygon per entry of `polygons`, taking each ring from `ring`.
+pub(crate) fn assemble<T, F>(
+    polygons: &[PolygonIndices],
+    mut ring: F,
+    winding: Winding,
+) -> MultiPolygon<T>
+where
+    T: CoordNum + Numeric,
+    F: FnMut(usize) -> LineString<T>,
+{
+    MultiPolygon(
+        polygons
+            .iter()
+            .map(|poly| {
+                let exterior = wind(ring(poly.exterior), false, winding);
+                let interiors = poly
+                    .interiors
+                    .iter()
+                    .map(|i| wind(ring(*i), true, winding))
+                    .collect();
+                Polygon::new(exterior, interiors)
+            })
+            .collect(),
+    )
 }
 
 impl<T> Collate<T> for MultiLineString<T>
 where
-    T: CoordNum + Numeric + fmt::Display + fmt::Debug,
+    T: CoordNum + Numeric + fmt::Debug,
 {
-    /// Collate an unsorted `MultiLineString` into a collated `MultiPolygon`.
-    /// Non-consuming, makes copies of `LineString`s.
-    fn collate(&self) -> CollateResult<MultiPolygon<T>> {
-        let (hole_of, exteriors) = get_poly_hole_map(&self)?;
-
-        let mut polys = HashMap::<usize, Polygon<T>>::new();
-
-        for (i, ls) in self.0.iter().enumerate() {
-            if exteriors.contains(&i) {
-                match polys.entry(i) {
-                    hash_map::Entry::Occupied(mut poly) => {
-                        let _val = poly
-                            .get_mut()
-                            .exterior_mut(|exterior| *exterior = ls.clone());
-                    }
-                    hash_map::Entry::Vacant(poly) => {
-                        let _val = poly.insert(Polygon::<T>::new(ls.clone(), vec![]));
-                    }
-                }
-            } else {
-                let exterior_i = hole_of.get(&i).ok_or(CollateError::IndexNotInMaps)?;
-                match polys.entry(*exterior_i) {
-                    hash_map::Entry::Occupied(mut poly) => {
-                        poly.get_mut().interiors_push(ls.clone())
-                    }
-                    hash_map::Entry::Vacant(poly) => {
-                        let _val =
-                            poly.insert(Polygon::<T>::new(LineString(vec![]), vec![ls.clone()]));
-                    }
-                }
-            }
+    fn collate_lenient_with(
+        &self,
+        options: &CollateOptions,
+    ) -> (MultiPolygon<T>, Vec<CollateIssue<T>>) {
+        if let Some(rule) = options.fill_rule {
+            let mut filled = fill(self, options, rule);
+            let (collated, more) = filled.rings.collate_lenient_with(&filled.options);
+            let mut issues = mem::take(&mut filled.issues);
+            issues.extend(more.into_iter().map(|issue| CollateIssue {
+                ring: filled.sources[issue.ring],
+                error: filled.to_sources(issue.error),
+            }));
+            issues.sort_by_key(|issue| issue.ring);
+            return (collated, issues);
         }
+        let (tree, issues) = build_tree_lenient(self, options);
+        let mut polygons = tree.polygons();
+        order_polygons(self, &mut polygons, options.order);
+        (
+            assemble(&polygons, |i| self.0[i].clone(), options.winding),
+            issues,
+        )
+    }
 
-        Ok(MultiPolygon(
-            polys
-                .into_iter()
-                .map(|(_i, p)| p)
-                .collect::<Vec<Polygon<T>>>(),
-        ))
+    fn collate_with(&self, options: &CollateOptions) -> CollateResult<MultiPolygon<T>, T> {
+        if let Some(rule) = options.fill_rule {
+            let filled = fill(self, options, rule);
+            if let Some(issue) = filled.issues.first() {
+                return Err(issue.error.clone());
+            }
+            return filled
+                .rings
+                .clone()
+                .collate_into_with(&filled.options)
+                .map_err(|error| filled.to_sources(error));
+        }
+        let polygons = self.collate_indices_with(options)?;
+        Ok(assemble(&polygons, |i| self.0[i].clone(), options.winding))
     }
 
-    /// Collate an unsorted `MultiLineString` into a collated `MultiPolygon`.
-    /// Consuming, does not copy the original `LineString`s (TODO: exteriors are sometimes copied due to working around a bug in the `exterior_mut` method on `Polygon`.
-    fn collate_into(self) -> CollateResult<MultiPolygon<T>> {
-        let (hole_of, exteriors) = get_poly_hole_map(&self)?;
-
-        let mut polys = HashMap::<usize, Polygon<T>>::new();
-
-        for (i, ls) in self.into_iter().enumerate() {
-            if exteriors.contains(&i) {
-                match polys.entry(i) {
-                    hash_map::Entry::Occupied(mut poly) => {
-                        let _val = poly
-                            .get_mut()
-                            // clone ls even though we are consuming self
-                            // because of a bug in exterior_mut
-                            .exterior_mut(|exterior| *exterior = ls.clone());
-                    }
-                    hash_map::Entry::Vacant(poly) => {
-                        let _val = poly.insert(Polygon::<T>::new(ls, vec![]));
-                    }
-                }
-            } else {
-                let exterior_i = hole_of.get(&i).ok_or(CollateError::IndexNotInMaps)?;
-                match polys.entry(*exterior_i) {
-                    hash_map::Entry::Occupied(mut poly) => poly.get_mut().interiors_push(ls),
-                    hash_map::Entry::Vacant(poly) => {
-                        let _val = poly.insert(Polygon::<T>::new(LineString(vec![]), vec![ls]));
-                    }
-                }
+    fn collate_by_depth_with(
+        &self,
+        options: &CollateOptions,
+    ) -> CollateResult<Vec<MultiPolygon<T>>, T> {
+        if let Some(rule) = options.fill_rule {
+            let filled = fill(self, options, rule);
+            if let Some(issue) = filled.issues.first() {
+                return Err(issue.error.clone());
             }
+            return filled
+                .rings
+                .collate_by_depth_with(&filled.options)
+                .map_err(|error| filled.to_sources(error));
         }
+        let mut depths = build_tree(self, options)?.polygons_by_depth();
+        Ok(depths
+            .iter_mut()
+            .map(|polygons| {
+                order_polygons(self, polygons, options.order);
+                assemble(polygons, |i| self.0[i].clone(), options.winding)
+            })
+            .collect())
+    }
 
-        Ok(MultiPolygon(
-            polys
-                .into_iter()
-                .map(|(_i, p)| p)
-                .collect::<Vec<Polygon<T>>>(),
+    fn collate_into_with(self, options: &CollateOptions) -> CollateResult<MultiPolygon<T>, T> {
+        if options.fill_rule.is_some() {
+            return self.collate_with(options);
+        }
+        let polygons = self.collate_indices_with(options)?;
+        let mut rings = self.0;
+        Ok(assemble(
+            &polygons,
+            |i| mem::replace(&mut rings[i], LineString(vec![])),
+            options.winding,
         ))
     }
 }
+
+impl<T> CollateRings<T> for MultiLineString<T>
+where
+    T: CoordNum + Numeric,
+{
+    fn collation_tree_with(&self, options: &CollateOptions) -> CollateResult<CollationTree, T> {
+        build_tree(self, options)
+    }
+
+    fn collate_indices_with(
+        &self,
+        options: &CollateOptions,
+    ) -> CollateResult<Vec<PolygonIndices>, T> {
+        let mut polygons = build_tree(self, options)?.polygons();
+        order_polygons(self, &mut polygons, options.order);
+        Ok(polygons)
+    }
+}
@@ -0,0 +1,486 @@
+use crate::collate::{crossing_point, ring_lines, CollateError, CollateResult};
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+use crate::sweep::{inside_segment, orientation, segments_cross};
+use geo_types::{Coord, CoordNum, Line, LineString, MultiLineString};
+use std::cmp::Ordering;
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+
+/// Which points overlapping rings fill, by their winding number: how many
+/// times the rings wind counter-clockwise around the point, less how many
+/// times they wind clockwise.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FillRule {
+    /// Points with an odd winding number.
+    EvenOdd,
+    /// Points with a winding number other than zero.
+    NonZero,
+    /// Points with a positive winding number.
+    Positive,
+    /// Points with a negative winding number.
+    Negative,
+}
+
+impl FillRule {
+    /// Whether the rule fills points with winding number `winding`.
+    pub fn fills(self, winding: i32) -> bool {
+        match self {
+            Self::EvenOdd => winding % 2 != 0,
+            Self::NonZero => winding != 0,
+            Self::Positive => winding > 0,
+            Self::Negative => winding < 0,
+        }
+    }
+}
+
+/// An edge of the rings once split wherever it meets another, so that edges
+/// only meet at their ends. Edges that coincide are merged into one.
+struct Piece<T>
+where
+    T: CoordNum,
+{
+    /// The lower end, or the left end if the piece is horizontal.
+    lower: Coord<T>,
+    upper: Coord<T>,
+    /// How many rings run up the piece, or right along it if it is
+    /// horizontal, less how many run the other way. Unless the piece is
+    /// horizontal, that is how much the winding number drops crossing it from
+    /// left to right.
+    winding: i32,
+    /// The lowest ring running along the piece.
+    ring: usize,
+}
+
+impl<T> Piece<T>
+where
+    T: CoordNum + Numeric,
+{
+    /// The piece from `start` to `end`, which `winding` rings run along that
+    /// way, the lowest being `ring`.
+    fn new(start: Coord<T>, end: Coord<T>, winding: i32, ring: usize) -> Self {
+        if start.y < end.y || (start.y == end.y && start.x < end.x) {
+            Piece {
+                lower: start,
+                upper: end,
+                winding,
+                ring,
+            }
+        } else {
+            Piece {
+                lower: end,
+                upper: start,
+                winding: -winding,
+                ring,
+            }
+        }
+    }
+
+    fn line(&self) -> Line<T> {
+        Line::new(self.lower, self.upper)
+    }
+}
+
+/// Key for looking up coordinates, which need not be hashable themselves.
+type Key = (u64, u64);
+
+fn key<T: CoordNum + Numeric>(c: Coord<T>) -> Key {
+    (c.x.to_float().to_bits(), c.y.to_float().to_bits())
+}
+
+/// Whether `c` lies on `line`, other than at its ends, or beside it no more
+/// than `tolerance` away.
+fn touches<T>(line: &Line<T>, c: Coord<T>, tolerance: f64) -> bool
+where
+    T: CoordNum + Numeric,
+{
+    if inside_segment(line.start, line.end, c) {
+        return true;
+    }
+    let (ax, ay) = (line.start.x.to_float(), line.start.y.to_float());
+    let (dx, dy) = (line.end.x.to_float() - ax, line.end.y.to_float() - ay);
+    let (cx, cy) = (c.x.to_float() - ax, c.y.to_float() - ay);
+    let length = dx.hypot(dy);
+    let along = (cx * dx + cy * dy) / length;
+    c != line.start
+        && c != line.end
+        && along > 0.0
+        && along < length
+        && (cx * dy - cy * dx).abs() / length <= tolerance
+}
+
+/// Split `edges`, each with the ring it belongs to, wherever they cross or
+/// touch each other, and merge the pieces that coincide.
+///
+/// A crossing can only be placed as exactly as `T` allows, so the pieces
+/// either side of it may bend enough to cross or touch others nearby.
+/// Splitting is repeated until no pieces cross or touch, bar at their ends.
+/// For floats, where several lines cross at a point, each pair may place the
+/// crossing a little differently, so crossings within a few hundred ulps of
+/// the largest coordinate of a point already placed are moved onto it, and
+/// pieces passing that close to the end of another are split there as if
+/// they touched it.
+fn split_edges<T>(edges: &[(Line<T>, usize)]) -> Vec<Piece<T>>
+where
+    T: CoordNum + Numeric,
+{
+    let largest = edges
+        .iter()
+        .flat_map(|(line, _)| [line.start, line.end])
+        .map(|c| c.x.to_float().abs().max(c.y.to_float().abs()))
+        .fold(0.0, f64::max);
+    let tolerance = largest * f64::EPSILON * 256.0;
+
+    let mut pieces = merge(
+        edges
+            .iter()
+            .map(|(line, ring)| Piece::new(line.start, line.end, 1, *ring)),
+    );
+    while let Some(split) = split_pieces(&pieces, tolerance) {
+        pieces = split;
+    }
+    pieces
+}
+
+/// Split `pieces` wherever they cross or touch each other, merging those
+/// that then coincide, or `None` if none do. Crossings are moved onto any
+/// end of a piece, or crossing, no more than `tolerance` from them.
+fn split_pieces<T>(pieces: &[Piece<T>], tolerance: f64) -> Option<Vec<Piece<T>>>
+where
+    T: CoordNum + Numeric,
+{
+    let mut placed = Grid::new(tolerance);
+    for piece in pieces {
+        placed.insert(piece.lower, piece.lower);
+        placed.insert(piece.upper, piece.upper);
+    }
+
+    let mut order: Vec<usize> = (0..pieces.len()).collect();
+    order.sort_by(|a, b| {
+        pieces[*a]
+            .lower
+            .y
+            .partial_cmp(&pieces[*b].lower.y)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let mut splits: Vec<Vec<Coord<T>>> = vec![vec![]; pieces.len()];
+    for (i, one) in order.iter().enumerate() {
+        let a = pieces[*one].line();
+        for two in order[i + 1..]
+            .iter()
+            .take_while(|two| pieces[**two].lower.y <= a.end.y)
+        {
+            let b = pieces[*two].line();
+            if segments_cross(a.start, a.end, b.start, b.end) {
+                let (x, y) = crossing_point(&a, &b);
+                let at = placed.snap(Coord { x, y });
+                splits[*one].push(at);
+                splits[*two].push(at);
+                continue;
+            }
+            for c in [b.start, b.end] {
+                if touches(&a, c, tolerance) {
+                    splits[*one].push(c);
+                }
+            }
+            for c in [a.start, a.end] {
+                if touches(&b, c, tolerance) {
+                    splits[*two].push(c);
+                }
+            }
+        }
+    }
+
+    let mut split = false;
+    let mut split_pieces: Vec<Piece<T>> = Vec::new();
+    for (piece, mut points) in pieces.iter().zip(splits) {
+        // Order the points along the piece. Rounded crossings may lie a
+        // little off it, so they are ordered by how far along it they are.
+        let line = piece.line();
+        let along = |c: &Coord<T>| {
+            (c.x.widen() - line.start.x.widen()) * (line.end.x.widen() - line.start.x.widen())
+                + (c.y.widen() - line.start.y.widen()) * (line.end.y.widen() - line.start.y.widen())
+        };
+        let length = along(&line.end);
+        points.retain(|c| along(c) > T::zero().widen() && along(c) < length);
+        points.sort_by(|a, b| along(a).partial_cmp(&along(b)).unwrap_or(Ordering::Equal));
+        points.insert(0, line.start);
+        points.push(line.end);
+        points.dedup();
+
+        split |= points.len() > 2;
+        split_pieces.extend(
+            points
+                .windows(2)
+                .map(|pair| Piece::new(pair[0], pair[1], piece.winding, piece.ring)),
+        );
+    }
+    split.then(|| merge(split_pieces))
+}
+
+/// Merge the `pieces` that coincide.
+fn merge<T>(pieces: impl IntoIterator<Item = Piece<T>>) -> Vec<Piece<T>>
+where
+    T: CoordNum + Numeric,
+{
+    let mut merged: Vec<Piece<T>> = Vec::new();
+    let mut index: HashMap<(Key, Key), usize> = HashMap::new();
+    for piece in pieces {
+        match index.entry((key(piece.lower), key(piece.upper))) {
+            Entry::Occupied(entry) => {
+                let into = &mut merged[*entry.get()];
+                into.winding += piece.winding;
+                into.ring = into.ring.min(piece.ring);
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(merged.len());
+                merged.push(piece);
+            }
+        }
+    }
+    merged
+}
+
+/// The winding number just right of `point`, given the `active` pieces
+/// crossing the sweep line through it. Pieces through `point` count as left
+/// of it if `through` says so.
+///
+/// Which side of a piece `point` is on is decided by `orientation`, as when
+/// splitting, rather than by where the piece crosses the sweep line, which
+/// for floats may round to the other side of a point close to the piece.
+fn winding_at<T>(
+    pieces: &[Piece<T>],
+    active: &[usize],
+    point: Coord<T>,
+    through: impl Fn(&Piece<T>) -> bool,
+) -> i32
+where
+    T: CoordNum + Numeric,
+{
+    -active
+        .iter()
+        .map(|piece| &pieces[*piece])
+        .filter(|piece| match orientation(piece.lower, piece.upper, point) {
+            Ordering::Less => true,
+            Ordering::Equal => through(piece),
+            Ordering::Greater => false,
+        })
+        .map(|piece| piece.winding)
+        .sum::<i32>()
+}
+
+/// The boundary of the area that `rule` fills in `lines`, as closed rings
+/// that neither cross nor share an edge. Exteriors are wound clockwise and
+/// holes counter-clockwise, so collating the rings gives the filled area.
+///
+/// Every ring is taken as closed. Rings are split wherever they cross or
+/// touch, and for integers crossings are rounded to the nearest point,
+/// splitting again wherever that makes pieces cross or touch anew.
+/// Rings with non-finite or out of range coordinates are ignored. Should the
+/// boundary fail to close, `UnclosedFill` is returned rather than the rings
+/// that did.
+///
+/// ```
+/// use geo_collate::{fill_rings, Collate, FillRule};
+/// use geo_types::{LineString, MultiLineString};
+///
+/// let one: LineString<i64> = vec![(0, 0), (0, 2), (2, 2), (2, 0), (0, 0)].into();
+/// let two: LineString<i64> = vec![(1, 1), (1, 3), (3, 3), (3, 1), (1, 1)].into();
+/// let overlapping = MultiLineString(vec![one, two]);
+///
+/// let union = fill_rings(&overlapping, FillRule::NonZero).unwrap();
+/// assert_eq!(union.0.len(), 1);
+/// assert_eq!(union.collate().unwrap().0.len(), 1);
+/// ```
+pub fn fill_rings<T>(
+    lines: &MultiLineString<T>,
+    rule: FillRule,
+) -> CollateResult<MultiLineString<T>, T>
+where
+    T: CoordNum + Numeric,
+{
+    let (rings, _, unclosed) = fill_sources(lines, rule);
+    match unclosed.first() {
+        Some(ring) => Err(CollateError::UnclosedFill { ring: *ring }),
+        None => Ok(rings),
+    }
+}
+
+/// The rings `fill_rings` returns, each with the lowest ring of `lines`
+/// running along any part of it, and the lowest ring of `lines` running along
+/// each part of the boundary that could not be closed.
+pub(crate) fn fill_sources<T>(
+    lines: &MultiLineString<T>,
+    rule: FillRule,
+) -> (MultiLineString<T>, Vec<usize>, Vec<usize>)
+where
+    T: CoordNum + Numeric,
+{
+    let edges: Vec<(Line<T>, usize)> = lines
+        .0
+        .iter()
+        .enumerate()
+        .filter(|(_, ls)| {
+            ls.0.iter()
+                .all(|c| c.x.is_finite() && c.y.is_finite() && c.x.in_range() && c.y.in_range())
+        })
+        .flat_map(|(ring, ls)| ring_lines(ls).map(move |line| (line, ring)))
+        .filter(|(line, _)| line.start != line.end)
+        .collect();
+    let pieces = split_edges(&edges);
+
+    // Sweep up across the pieces, which no longer cross, working out the
+    // winding number on either side of each: left and right of those that
+    // aren't horizontal, below and above those that are.
+    let horizontal = |i: &usize| pieces[*i].lower.y == pieces[*i].upper.y;
+    let cmp = |a: &T, b: &T| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+
+    let mut starts: Vec<usize> = (0..pieces.len()).collect();
+    starts.sort_by(|a, b| cmp(&pieces[*a].lower.y, &pieces[*b].lower.y));
+    let mut starts = starts.into_iter().peekable();
+
+    let mut sides = vec![(0, 0); pieces.len()];
+    let mut active: Vec<usize> = Vec::new();
+    while let Some(y) = starts.peek().map(|i| pieces[*i].lower.y) {
+        let row: Vec<usize> =
+            std::iter::from_fn(|| starts.next_if(|i| pieces[*i].lower.y == y)).collect();
+        let flat: Vec<usize> = row.iter().copied().filter(horizontal).collect();
+
+        // Pieces ending below the row, where nothing started, are still
+        // active, but they no longer cross the sweep line. Of the pieces
+        // crossing it, those through the left end of a horizontal piece are
+        // left of its middle, having no other point in common with it.
+        active.retain(|i| pieces[*i].upper.y >= y);
+        for i in &flat {
+            sides[*i].0 = winding_at(&pieces, &active, pieces[*i].lower, |_| true);
+        }
+        active.retain(|i| pieces[*i].upper.y > y);
+        active.extend(row.iter().copied().filter(|i| !horizontal(i)));
+        for i in &flat {
+            sides[*i].1 = winding_at(&pieces, &active, pieces[*i].lower, |_| true);
+        }
+
+        for i in row.iter().filter(|i| !horizontal(i)) {
+            let piece = &pieces[*i];
+            let left = winding_at(&pieces, &active, piece.lower, |other| {
+                orientation(piece.lower, piece.upper, other.upper) == Ordering::Greater
+            });
+            sides[*i] = (left, left - piece.winding);
+        }
+    }
+
+    // Keep the pieces with the filled area on one side only, running with
+    // it on their right.
+    let boundary: Vec<(Coord<T>, Coord<T>, usize)> = pieces
+        .iter()
+        .zip(&sides)
+        .filter_map(|(piece, (one, two))| {
+            let (one, two) = (rule.fills(*one), rule.fills(*two));
+            if one == two {
+                return None;
+            }
+            // Filled right of a piece running up, or below one running
+            // right.
+            let flat = piece.lower.y == piece.upper.y;
+            if two != flat {
+                Some((piece.lower, piece.upper, piece.ring))
+            } else {
+                Some((piece.upper, piece.lower, piece.ring))
+            }
+        })
+        .collect();
+
+    let (closed, unclosed) = chain_boundary(&boundary);
+    let (rings, sources) = closed.into_iter().unzip();
+    (MultiLineString(rings), sources, unclosed)
+}
+
+/// Join the directed `boundary` pieces, each with its lowest ring, into
+/// closed rings, each with the lowest ring of its pieces. Where several
+/// pieces leave a vertex, the one turning furthest right is taken, keeping to
+/// the same filled area, and any loop a ring makes through a vertex it
+/// already visited is split off as a ring of its own, so that no ring visits
+/// a vertex twice. The lowest ring of each chain that runs into a dead end
+/// before closing is returned separately.
+fn chain_boundary<T>(
+    boundary: &[(Coord<T>, Coord<T>, usize)],
+) -> (Vec<(LineString<T>, usize)>, Vec<usize>)
+where
+    T: CoordNum + Numeric,
+{
+    let mut leaving: HashMap<Key, Vec<usize>> = HashMap::new();
+    for (i, (start, _, _)) in boundary.iter().enumerate() {
+        leaving.entry(key(*start)).or_default().push(i);
+    }
+    let angle = |from: Coord<T>, to: Coord<T>| {
+        (to.y.to_float() - from.y.to_float()).atan2(to.x.to_float() - from.x.to_float())
+    };
+
+    let mut used = vec![false; boundary.len()];
+    let mut rings = Vec::new();
+    let mut unclosed = Vec::new();
+    for seed in 0..boundary.len() {
+        if used[seed] {
+            continue;
+        }
+        let mut path = vec![boundary[seed].0];
+        // The ring of each piece along `path`.
+        let mut path_rings: Vec<usize> = Vec::new();
+        let mut visited: HashMap<Key, usize> = HashMap::from([(key(path[0]), 0)]);
+        let mut piece = seed;
+        loop {
+            used[piece] = true;
+            let (from, to, source) = boundary[piece];
+            if let Some(at) = visited.get(&key(to)).copied() {
+                let mut ring: Vec<Coord<T>> = path.split_off(at);
+                for c in &ring[1..] {
+                    visited.remove(&key(*c));
+                }
+                ring.push(to);
+                let lowest = path_rings
+                    .split_off(at)
+                    .into_iter()
+                    .fold(source, usize::min);
+                rings.push((LineString(ring), lowest));
+                path.push(to);
+                if at == 0 {
+                    break;
+                }
+            } else {
+                visited.insert(key(to), path.len());
+                path.push(to);
+                path_rings.push(source);
+            }
+
+            // Turn as far right as possible: the first piece leaving `to`
+            // counter-clockwise from the way back.
+            let back = angle(to, from);
+            let next = leaving.get(&key(to)).and_then(|candidates| {
+                candidates
+                    .iter()
+                    .filter(|i| !used[**i])
+                    .min_by(|a, b| {
+                        let turn = |i: usize| {
+                            let turn = angle(to, boundary[i].1) - back;
+                            if turn <= 0.0 {
+                                turn + std::f64::consts::TAU
+                            } else {
+                                turn
+                            }
+                        };
+                        turn(**a).total_cmp(&turn(**b)).then(a.cmp(b))
+                    })
+                    .copied()
+            });
+            match next {
+                Some(next) => piece = next,
+                None => {
+                    unclosed.push(path_rings.into_iter().fold(usize::MAX, usize::min));
+                    break;
+                }
+            }
+        }
+    }
+    (rings, unclosed)
+}
@@ -0,0 +1,89 @@
+use crate::numeric::Numeric;
+use geo_types::{Coord, CoordNum};
+use std::collections::HashMap;
+
+/// Values placed at points, bucketed on a grid of square cells `size` wide
+/// so that only the cells around a point need searching for those near it.
+/// With a `size` of zero, values are bucketed by their exact point instead.
+pub(crate) struct Grid<V> {
+    size: f64,
+    cells: HashMap<(i64, i64), Vec<V>>,
+}
+
+impl<V> Grid<V> {
+    pub(crate) fn new(size: f64) -> Self {
+        Grid {
+            size,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// The cell `c` falls in. Casting saturates, so points too far out for
+    /// a cell index share the cells along the edge of the grid.
+    fn cell<T>(&self, c: Coord<T>) -> (i64, i64)
+    where
+        T: CoordNum + Numeric,
+    {
+        if self.size > 0.0 {
+            (
+                (c.x.to_float() / self.size).floor() as i64,
+                (c.y.to_float() / self.size).floor() as i64,
+            )
+        } else {
+            (
+                c.x.to_float().to_bits() as i64,
+                c.y.to_float().to_bits() as i64,
+            )
+        }
+    }
+
+    pub(crate) fn insert<T>(&mut self, c: Coord<T>, value: V)
+    where
+        T: CoordNum + Numeric,
+    {
+        let cell = self.cell(c);
+        self.cells.entry(cell).or_default().push(value);
+    }
+
+    /// The values in the cell of `c` and the cells around it, which include
+    /// every value placed no more than `size` from `c`.
+    pub(crate) fn around<T>(&self, c: Coord<T>) -> impl Iterator<Item = &V>
+    where
+        T: CoordNum + Numeric,
+    {
+        let (x, y) = self.cell(c);
+        let reach = i64::from(self.size > 0.0);
+        (x.saturating_sub(reach)..=x.saturating_add(reach))
+            .flat_map(move |x| {
+                (y.saturating_sub(reach)..=y.saturating_add(reach)).map(move |y| (x, y))
+            })
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+    }
+}
+
+impl<T> Grid<Coord<T>>
+where
+    T: CoordNum + Numeric,
+{
+    /// The first point placed no more than `size` from `c`, or `c` itself,
+    /// placed now, if there is none.
+    pub(crate) fn snap(&mut self, c: Coord<T>) -> Coord<T> {
+        let near = self
+            .around(c)
+            .find(|other| distance(c, **other) <= self.size)
+            .copied();
+        near.unwrap_or_else(|| {
+            self.insert(c, c);
+            c
+        })
+    }
+}
+
+/// The distance between `a` and `b`.
+pub(crate) fn distance<T>(a: Coord<T>, b: Coord<T>) -> f64
+where
+    T: CoordNum + Numeric,
+{
+    (a.x.to_float() - b.x.to_float()).hypot(a.y.to_float() - b.y.to_float())
+}
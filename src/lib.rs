@@ -3,8 +3,20 @@
 
 extern crate geo_types;
 
+pub mod chain;
 pub mod collate;
-mod numeric;
+pub mod fill;
+mod grid;
+pub mod numeric;
+pub mod quantize;
+mod sweep;
+#[allow(clippy::module_inception)]
 mod test;
+pub mod tree;
 
+pub use crate::chain::*;
 pub use crate::collate::*;
+pub use crate::fill::*;
+pub use crate::numeric::*;
+pub use crate::quantize::*;
+pub use crate::tree::*;
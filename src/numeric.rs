@@ -1,51 +1,255 @@
-pub trait Numeric {
-    fn half(self) -> Self;
-    fn as_float(self) -> f64;
-    fn as_int(self) -> i64;
-    fn from_float(x: f64) -> Self;
-    fn from_int(i: i64) -> Self;
-}
+use std::ops::{Add, Div, Mul, Neg, Sub};
 
-impl Numeric for i64 {
-    fn half(self) -> i64 {
-        self / 2
-    }
+/// Coordinate types collation can work with. It is implemented for every
+/// primitive integer and float type, and can be implemented for other
+/// `CoordNum` types, such as unit-typed newtypes or fixed-point wrappers, to
+/// collate them directly.
+///
+/// Sweep intersections are compared by cross-multiplying coordinate
+/// differences in `Wide`, which is signed, so unsigned coordinates never
+/// underflow. For that to be exact and free of overflow, integers of up to
+/// 32 bits may take any value, while 64 and 128 bit integers must be within
+/// ±2^40 (about ±1.1e12), with `i128` as `Wide`. Floats may take any finite
+/// value, computed in `f64`, but compare only as exactly as floating point
+/// allows. Coordinates outside the range are reported as
+/// `CollateError::OutOfRange`.
+///
+/// A newtype over a primitive can delegate every method to it:
+///
+/// ```
+/// # use geo_collate::{Collate, Numeric};
+/// # use geo_types::{LineString, MultiLineString};
+/// #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+/// struct Micrometers(i64);
+/// #
+/// # macro_rules! op {
+/// #     ($($op:ident, $method:ident;)*) => {
+/// #         $(
+/// #             impl std::ops::$op for Micrometers {
+/// #                 type Output = Micrometers;
+/// #
+/// #                 fn $method(self, other: Micrometers) -> Micrometers {
+/// #                     Micrometers(std::ops::$op::$method(self.0, other.0))
+/// #                 }
+/// #             }
+/// #         )*
+/// #     };
+/// # }
+/// #
+/// # op!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem;);
+/// #
+/// # impl num_traits::Zero for Micrometers {
+/// #     fn zero() -> Self {
+/// #         Micrometers(0)
+/// #     }
+/// #
+/// #     fn is_zero(&self) -> bool {
+/// #         self.0 == 0
+/// #     }
+/// # }
+/// #
+/// # impl num_traits::One for Micrometers {
+/// #     fn one() -> Self {
+/// #         Micrometers(1)
+/// #     }
+/// # }
+/// #
+/// # impl num_traits::Num for Micrometers {
+/// #     type FromStrRadixErr = std::num::ParseIntError;
+/// #
+/// #     fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+/// #         i64::from_str_radix(s, radix).map(Micrometers)
+/// #     }
+/// # }
+/// #
+/// # impl num_traits::ToPrimitive for Micrometers {
+/// #     fn to_i64(&self) -> Option<i64> {
+/// #         Some(self.0)
+/// #     }
+/// #
+/// #     fn to_u64(&self) -> Option<u64> {
+/// #         self.0.to_u64()
+/// #     }
+/// # }
+/// #
+/// # impl num_traits::NumCast for Micrometers {
+/// #     fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+/// #         n.to_i64().map(Micrometers)
+/// #     }
+/// # }
+///
+/// impl Numeric for Micrometers {
+///     type Wide = i128;
+///
+///     fn to_float(self) -> f64 {
+///         self.0.to_float()
+///     }
+///
+///     fn from_float(x: f64) -> Self {
+///         Micrometers(i64::from_float(x))
+///     }
+///
+///     fn in_range(self) -> bool {
+///         self.0.in_range()
+///     }
+///
+///     fn widen(self) -> i128 {
+///         self.0.widen()
+///     }
+///
+///     fn from_wide(wide: i128) -> Self {
+///         Micrometers(i64::from_wide(wide))
+///     }
+///
+///     fn wide_to_float(wide: i128) -> f64 {
+///         i64::wide_to_float(wide)
+///     }
+/// }
+///
+/// let square: LineString<Micrometers> = vec![(0, 0), (0, 5), (5, 5), (5, 0), (0, 0)]
+///     .into_iter()
+///     .map(|(x, y)| (Micrometers(x), Micrometers(y)))
+///     .collect();
+/// let polygons = MultiLineString(vec![square]).collate().unwrap();
+/// assert_eq!(polygons.0.len(), 1);
+/// ```
+///
+/// Integers round `from_float` to the nearest value, so a newtype over a
+/// fixed-point integer should too.
+///
+/// A type with its own `Wide`, such as a rational, must keep `Wide`
+/// arithmetic on coordinates within range exact for comparisons to be exact,
+/// or at least consistent.
+pub trait Numeric: Copy {
+    /// A type products of coordinate differences are computed in. For
+    /// integers it is wide enough for them to be exact.
+    type Wide: Copy
+        + PartialOrd
+        + Add<Output = Self::Wide>
+        + Sub<Output = Self::Wide>
+        + Mul<Output = Self::Wide>
+        + Div<Output = Self::Wide>
+        + Neg<Output = Self::Wide>;
 
-    fn as_float(self) -> f64 {
-        self as f64
+    /// The coordinate as a float, for tolerances, areas and the like, where
+    /// exactness isn't needed.
+    fn to_float(self) -> f64;
+    /// The coordinate nearest `x`, where crossings and snapped points are
+    /// computed as floats.
+    fn from_float(x: f64) -> Self;
+    /// Whether the coordinate is a number at all. Only floats can fail.
+    fn is_finite(self) -> bool {
+        true
     }
+    /// Whether the coordinate is within the range collation supports, for
+    /// `Wide` arithmetic on it to be exact.
+    fn in_range(self) -> bool;
+    /// The coordinate as a `Wide`, for exact arithmetic.
+    fn widen(self) -> Self::Wide;
+    /// The coordinate nearest `wide`, rounded toward zero.
+    fn from_wide(wide: Self::Wide) -> Self;
+    /// `wide` as a float.
+    fn wide_to_float(wide: Self::Wide) -> f64;
 
-    fn as_int(self) -> i64 {
-        self
+    /// Where the line through (`x0`, `y0`) that moves `dx` right for every
+    /// `dy` up, with `dy` positive, crosses `y`, as a numerator and a
+    /// positive denominator. Integers keep it an exact fraction.
+    fn crossing(
+        x0: Self::Wide,
+        y0: Self::Wide,
+        dx: Self::Wide,
+        dy: Self::Wide,
+        y: Self::Wide,
+    ) -> (Self::Wide, Self::Wide) {
+        (x0 * dy + (y - y0) * dx, dy)
     }
+}
 
-    fn from_float(x: f64) -> Self {
-        x as i64
-    }
+/// Implement `Numeric` for integer types computing in `$wide`, whose
+/// coordinates are in range if `$in_range` says so.
+macro_rules! impl_numeric_int {
+    ($wide:ty, $in_range:expr; $($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                type Wide = $wide;
 
-    fn from_int(x: i64) -> Self {
-        x
-    }
+                fn to_float(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_float(x: f64) -> Self {
+                    x.round() as $t
+                }
+
+                fn in_range(self) -> bool {
+                    let in_range: fn($t) -> bool = $in_range;
+                    in_range(self)
+                }
+
+                fn widen(self) -> $wide {
+                    self as $wide
+                }
+
+                fn from_wide(wide: $wide) -> Self {
+                    wide as $t
+                }
+
+                fn wide_to_float(wide: $wide) -> f64 {
+                    wide as f64
+                }
+            }
+        )*
+    };
 }
 
-impl Numeric for f64 {
-    fn half(self) -> f64 {
-        self / 2.0
-    }
+impl_numeric_int!(i64, |_| true; i8, i16, u8, u16);
+impl_numeric_int!(i128, |_| true; i32, u32);
+impl_numeric_int!(i128, |c| c.unsigned_abs() <= 1 << 40; i64, i128, isize);
+impl_numeric_int!(i128, |c| c <= 1 << 40; u64, u128, usize);
 
-    fn as_float(self) -> f64 {
-        self
-    }
+/// Implement `Numeric` for float types, which compute in `f64`.
+macro_rules! impl_numeric_float {
+    ($($t:ty),*) => {
+        $(
+            impl Numeric for $t {
+                type Wide = f64;
 
-    fn as_int(self) -> i64 {
-        self as i64
-    }
+                fn to_float(self) -> f64 {
+                    self as f64
+                }
 
-    fn from_float(x: f64) -> Self {
-        x
-    }
+                fn from_float(x: f64) -> Self {
+                    x as $t
+                }
 
-    fn from_int(x: i64) -> Self {
-        x as f64
-    }
+                fn is_finite(self) -> bool {
+                    <$t>::is_finite(self)
+                }
+
+                fn in_range(self) -> bool {
+                    true
+                }
+
+                fn widen(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_wide(wide: f64) -> Self {
+                    wide as $t
+                }
+
+                fn wide_to_float(wide: f64) -> f64 {
+                    wide
+                }
+
+                /// Floats divide the crossing out, so that a crossing at `y0`
+                /// is exactly `x0`.
+                fn crossing(x0: f64, y0: f64, dx: f64, dy: f64, y: f64) -> (f64, f64) {
+                    (x0 + (y - y0) * dx / dy, 1.0)
+                }
+            }
+        )*
+    };
 }
+
+impl_numeric_float!(f32, f64);
@@ -0,0 +1,156 @@
+use crate::collate::{
+    assemble, is_degenerate, CollateError, CollateOptions, CollateResult, CollateRings, OpenRings,
+};
+use crate::numeric::Numeric;
+use crate::tree::PolygonIndices;
+use geo_types::{Coord, CoordNum, LineString, MultiLineString, MultiPolygon};
+use std::collections::HashMap;
+
+/// Polygons collated on an integer grid, and what the grid did to the rings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Quantized<T>
+where
+    T: CoordNum,
+{
+    /// The collated polygons, made of the input rings as they were.
+    pub polygons: MultiPolygon<T>,
+    /// Rings that enclose an area, but not on the grid, in input order. They
+    /// are left out.
+    pub collapsed: Vec<usize>,
+    /// Rings that land on the same grid points as an earlier ring, each with
+    /// the earlier ring, in input order. They are left out.
+    pub merged: Vec<(usize, usize)>,
+}
+
+/// `ls` on the grid, as the points it visits in order from its least, in
+/// whichever direction comes first, or `None` if it visits fewer than three.
+/// Rings visiting the same points either way round get the same key.
+fn ring_key(ls: &LineString<i64>) -> Option<Vec<(i64, i64)>> {
+    let mut points: Vec<(i64, i64)> = ls.0.iter().map(|c| c.x_y()).collect();
+    points.dedup();
+    while points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+    if points.len() < 3 {
+        return None;
+    }
+
+    let from_least = |mut points: Vec<(i64, i64)>| {
+        let least = (0..points.len()).min_by_key(|i| points[*i]).unwrap_or(0);
+        points.rotate_left(least);
+        points
+    };
+    let forward = from_least(points.clone());
+    points.reverse();
+    let backward = from_least(points);
+    Some(forward.min(backward))
+}
+
+/// Collate `lines` on an integer grid of `scale` points per unit, for the
+/// exactness of integer collation with coordinates in any units, such as
+/// millimetres as `f64` with a `scale` of 1e6 for a nanometre grid.
+///
+/// Every coordinate is rounded to the nearest grid point and the rings are
+/// collated as `i64` with `options`, whose `epsilon` and
+/// `OpenRings::CloseWithin` distances, being in the units of `lines`, are
+/// scaled onto the grid along with the coordinates. The
+/// polygons are then built from the input rings, not the rounded ones.
+/// Rings that collapse on the grid, or land on the same grid points as an
+/// earlier ring, are reported and left out.
+///
+/// Errors give ring indices into `lines`, and coordinates in its units. A
+/// `scale` that is not positive and finite is `CollateError::InvalidScale`,
+/// and a fill rule in `options` is `CollateError::FillRuleNotSupported`.
+///
+/// ```
+/// use geo_collate::{collate_quantized, CollateOptions};
+/// use geo_types::{LineString, MultiLineString};
+///
+/// let exterior: LineString<f64> =
+///     vec![(0.0, 0.0), (0.0, 3.0), (3.0, 3.0), (3.0, 0.0), (0.0, 0.0)].into();
+/// let hole: LineString<f64> =
+///     vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0), (1.0, 1.0)].into();
+/// let speck: LineString<f64> =
+///     vec![(1e-9, 0.5), (1e-9, 0.5 + 1e-9), (2e-9, 0.5), (1e-9, 0.5)].into();
+/// let lines = MultiLineString(vec![exterior, hole, speck]);
+///
+/// let quantized = collate_quantized(&lines, 1e6, &CollateOptions::new()).unwrap();
+/// assert_eq!(quantized.polygons.0.len(), 1);
+/// assert_eq!(quantized.collapsed, vec![2]);
+/// ```
+pub fn collate_quantized<T>(
+    lines: &MultiLineString<T>,
+    scale: f64,
+    options: &CollateOptions,
+) -> CollateResult<Quantized<T>, T>
+where
+    T: CoordNum + Numeric,
+{
+    if !(scale.is_finite() && scale > 0.0) {
+        return Err(CollateError::InvalidScale { scale });
+    }
+    if options.fill_rule.is_some() {
+        return Err(CollateError::FillRuleNotSupported);
+    }
+
+    let mut collapsed = Vec::new();
+    let mut merged = Vec::new();
+    let mut kept: Vec<usize> = Vec::new();
+    let mut grid: Vec<LineString<i64>> = Vec::new();
+    let mut keys: HashMap<Vec<(i64, i64)>, usize> = HashMap::new();
+
+    for (ring, ls) in lines.0.iter().enumerate() {
+        if let Some(vertex) =
+            ls.0.iter()
+                .position(|c| !(c.x.is_finite() && c.y.is_finite()))
+        {
+            return Err(CollateError::NonFinite { ring, vertex });
+        }
+        let on_grid: LineString<i64> =
+            ls.0.iter()
+                .map(|c| Coord {
+                    x: (c.x.to_float() * scale).round() as i64,
+                    y: (c.y.to_float() * scale).round() as i64,
+                })
+                .collect();
+
+        if is_degenerate(&on_grid) && !is_degenerate(ls) {
+            collapsed.push(ring);
+            continue;
+        }
+        if let Some(key) = ring_key(&on_grid) {
+            if let Some(earlier) = keys.get(&key) {
+                merged.push((ring, *earlier));
+                continue;
+            }
+            keys.insert(key, ring);
+        }
+        kept.push(ring);
+        grid.push(on_grid);
+    }
+
+    let on_grid = CollateOptions {
+        open_rings: match options.open_rings {
+            OpenRings::CloseWithin(within) => OpenRings::CloseWithin(within * scale),
+            open_rings => open_rings,
+        },
+        epsilon: options.epsilon * scale,
+        ..options.clone()
+    };
+    let from_grid = |c: i64| T::from_float(c as f64 / scale);
+    let polygons: Vec<PolygonIndices> = MultiLineString(grid)
+        .collate_indices_with(&on_grid)
+        .map_err(|error| error.map_rings(|ring| kept[ring]).map_coords(from_grid))?
+        .into_iter()
+        .map(|polygon| PolygonIndices {
+            exterior: kept[polygon.exterior],
+            interiors: polygon.interiors.iter().map(|ring| kept[*ring]).collect(),
+        })
+        .collect();
+
+    Ok(Quantized {
+        polygons: assemble(&polygons, |i| lines.0[i].clone(), options.winding),
+        collapsed,
+        merged,
+    })
+}
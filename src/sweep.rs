@@ -0,0 +1,680 @@
+use crate::collate::{ring_lines, signed_area, CollateError, CollateResult, OrientationPolicy};
+use crate::grid::Grid;
+use crate::numeric::Numeric;
+use geo_types::{Coord, CoordNum, Line, LineString, MultiLineString};
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::ops::Mul;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum UpDown {
+    Up,
+    Down,
+}
+
+/// The fraction `numerator / denominator`, with a positive denominator. For
+/// integers both are exact, so fractions compare exactly by
+/// cross-multiplying.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Fraction<W> {
+    pub(crate) numerator: W,
+    pub(crate) denominator: W,
+}
+
+impl<W> Fraction<W>
+where
+    W: Copy + PartialOrd + Mul<Output = W>,
+{
+    pub(crate) fn cmp(&self, other: &Self) -> Ordering {
+        (self.numerator * other.denominator)
+            .partial_cmp(&(other.numerator * self.denominator))
+            .unwrap_or(Ordering::Equal)
+    }
+}
+
+/// A non-horizontal edge of a ring, from its lower end to its upper end.
+#[derive(Debug)]
+pub(crate) struct SweepEdge<T>
+where
+    T: CoordNum,
+{
+    pub(crate) lower: Coord<T>,
+    pub(crate) upper: Coord<T>,
+    /// Whether the ring runs up or down the edge.
+    pub(crate) direction: UpDown,
+    pub(crate) ring: usize,
+}
+
+impl<T> SweepEdge<T>
+where
+    T: CoordNum + Numeric,
+{
+    /// The edge `line` of `ring`, or `None` if it is horizontal.
+    pub(crate) fn new(line: Line<T>, ring: usize) -> Option<Self> {
+        let (direction, lower, upper) = match line.start.y.partial_cmp(&line.end.y)? {
+            Ordering::Less => (UpDown::Up, line.start, line.end),
+            Ordering::Greater => (UpDown::Down, line.end, line.start),
+            Ordering::Equal => return None,
+        };
+        Some(SweepEdge {
+            lower,
+            upper,
+            direction,
+            ring,
+        })
+    }
+
+    /// Where the edge crosses y = `y`, measured from its upper end if `y` is
+    /// there so that edges meeting at a vertex cross exactly at the vertex.
+    pub(crate) fn at(&self, y: T) -> Fraction<T::Wide> {
+        let from = if y == self.upper.y {
+            self.upper
+        } else {
+            self.lower
+        };
+        let slope = self.slope();
+        let (numerator, denominator) = T::crossing(
+            from.x.widen(),
+            from.y.widen(),
+            slope.numerator,
+            slope.denominator,
+            y.widen(),
+        );
+        Fraction {
+            numerator,
+            denominator,
+        }
+    }
+
+    /// How far right the edge moves per unit of y.
+    pub(crate) fn slope(&self) -> Fraction<T::Wide> {
+        Fraction {
+            numerator: self.upper.x.widen() - self.lower.x.widen(),
+            denominator: self.upper.y.widen() - self.lower.y.widen(),
+        }
+    }
+
+    /// The x the edge crosses y = `y` at, rounded toward zero for integers.
+    fn x(&self, y: T) -> T {
+        let at = self.at(y);
+        T::from_wide(at.numerator / at.denominator)
+    }
+}
+
+/// Which side of the directed line from `a` to `b` the point `c` is on:
+/// `Greater` to the left, `Less` to the right, `Equal` on it.
+pub(crate) fn orientation<T>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> Ordering
+where
+    T: CoordNum + Numeric,
+{
+    let cross = (b.x.widen() - a.x.widen()) * (c.y.widen() - a.y.widen())
+        - (b.y.widen() - a.y.widen()) * (c.x.widen() - a.x.widen());
+    cross
+        .partial_cmp(&T::zero().widen())
+        .unwrap_or(Ordering::Equal)
+}
+
+/// Whether the segments from `a` to `b` and from `c` to `d` cross at a
+/// single point inside both. Segments that only touch do not cross.
+pub(crate) fn segments_cross<T>(a: Coord<T>, b: Coord<T>, c: Coord<T>, d: Coord<T>) -> bool
+where
+    T: CoordNum + Numeric,
+{
+    let opposite = |one: Ordering, two: Ordering| one != Ordering::Equal && one == two.reverse();
+    opposite(orientation(a, b, c), orientation(a, b, d))
+        && opposite(orientation(c, d, a), orientation(c, d, b))
+}
+
+/// Whether `c` lies on the segment from `a` to `b`, other than at its ends.
+pub(crate) fn inside_segment<T>(a: Coord<T>, b: Coord<T>, c: Coord<T>) -> bool
+where
+    T: CoordNum + Numeric,
+{
+    let between = |p: T, q: T, r: T| (p <= r && r <= q) || (q <= r && r <= p);
+    c != a
+        && c != b
+        && orientation(a, b, c) == Ordering::Equal
+        && between(a.x, b.x, c.x)
+        && between(a.y, b.y, c.y)
+}
+
+/// Move every vertex onto the first vertex seen, across all rings, that is
+/// no more than `epsilon` away from it.
+fn snap_vertices<T>(polys: &MultiLineString<T>, epsilon: f64) -> MultiLineString<T>
+where
+    T: CoordNum + Numeric,
+{
+    let mut seen = Grid::new(epsilon);
+    polys
+        .0
+        .iter()
+        .map(|ls| {
+            ls.0.iter()
+                .map(|c| seen.snap(*c))
+                .collect::<LineString<T>>()
+        })
+        .collect()
+}
+
+/// The vertices of `ls`, left open, without repeats and without spikes,
+/// where the ring runs out and straight back along itself. Neither changes
+/// what the ring encloses, but a spike's two sides would cross the sweep line
+/// at the same point. A ring enclosing nothing is left with at most two
+/// vertices.
+fn strip_spikes<T>(ls: &LineString<T>) -> Vec<Coord<T>>
+where
+    T: CoordNum + Numeric,
+{
+    let is_spike = |a: Coord<T>, v: Coord<T>, b: Coord<T>| {
+        let dot = (v.x.widen() - a.x.widen()) * (b.x.widen() - v.x.widen())
+            + (v.y.widen() - a.y.widen()) * (b.y.widen() - v.y.widen());
+        orientation(a, v, b) == Ordering::Equal && dot < T::zero().widen()
+    };
+
+    let mut coords: Vec<Coord<T>> = Vec::with_capacity(ls.0.len());
+    for c in ls.0.iter().copied() {
+        loop {
+            match coords[..] {
+                [.., last] if last == c => break,
+                [.., a, v] if is_spike(a, v, c) => {
+                    coords.pop();
+                }
+                _ => {
+                    coords.push(c);
+                    break;
+                }
+            }
+        }
+    }
+
+    // The ring may also double back where it closes.
+    loop {
+        match coords[..] {
+            [first, .., last] if first == last => {
+                coords.pop();
+            }
+            [first, .., a, v] if is_spike(a, v, first) => {
+                coords.pop();
+            }
+            [first, second, .., last] if is_spike(last, first, second) => {
+                coords.remove(0);
+            }
+            _ => break,
+        }
+    }
+    coords
+}
+
+/// What the sweep found out about every ring.
+#[derive(Debug)]
+pub(crate) struct Nesting {
+    /// The ring directly enclosing each ring.
+    pub(crate) parents: Vec<Option<usize>>,
+    /// Whether the sweep reached each ring at all.
+    pub(crate) reached: Vec<bool>,
+}
+
+/// State of the plane sweep: the edges currently crossing the sweep line, in
+/// order along it, and what is known so far about the rings reached.
+struct Sweep<T>
+where
+    T: CoordNum,
+{
+    edges: Vec<SweepEdge<T>>,
+    active: Vec<usize>,
+    epsilon: f64,
+    parents: Vec<Option<usize>>,
+    clockwise: Vec<bool>,
+    /// The area each ring encloses.
+    areas: Vec<f64>,
+    reached: Vec<bool>,
+}
+
+impl<T> Sweep<T>
+where
+    T: CoordNum + Numeric,
+{
+    /// Order edges `a` and `b` along the sweep line just above y = `y`, or
+    /// just below it if `above` is false. Crossings no more than `epsilon`
+    /// apart count as the same point.
+    ///
+    /// Edges that coincide, where rings touch along an edge, are ordered so
+    /// that every ring is left before the next is entered: first those with
+    /// their ring's inside to their left, smallest ring first, then those with
+    /// it to their right, largest ring first. A ring inside another is
+    /// smaller, so it is left before, and entered after, the ring around it.
+    /// Any remaining ties go by ring and edge index. Edges no more than
+    /// `epsilon` apart all along the sweep lines they share coincide too, even
+    /// if their slopes differ.
+    fn cmp_edges(&self, a: usize, b: usize, y: T, above: bool) -> Ordering {
+        let tie = |edge: usize| {
+            let ring = self.edges[edge].ring;
+            let entering = self.inside_right(edge);
+            let area = if entering {
+                -self.areas[ring]
+            } else {
+                self.areas[ring]
+            };
+            (entering, area, ring, edge)
+        };
+        let (tie_a, tie_b) = (tie(a), tie(b));
+        let coincident = self.coincident(a, b);
+        let (a, b) = (&self.edges[a], &self.edges[b]);
+        self.cmp_at(a.at(y), b.at(y))
+            .then_with(|| {
+                if coincident {
+                    return Ordering::Equal;
+                }
+                let slope = a.slope().cmp(&b.slope());
+                if above {
+                    slope
+                } else {
+                    slope.reverse()
+                }
+            })
+            .then(tie_a.0.cmp(&tie_b.0))
+            .then(tie_a.1.total_cmp(&tie_b.1))
+            .then((tie_a.2, tie_a.3).cmp(&(tie_b.2, tie_b.3)))
+    }
+
+    /// Compare two crossings of the sweep line, treating crossings no more
+    /// than `epsilon` apart as the same point.
+    fn cmp_at(&self, a: Fraction<T::Wide>, b: Fraction<T::Wide>) -> Ordering {
+        let float = |at: Fraction<T::Wide>| {
+            T::wide_to_float(at.numerator) / T::wide_to_float(at.denominator)
+        };
+        if self.epsilon > 0.0 && (float(a) - float(b)).abs() <= self.epsilon {
+            Ordering::Equal
+        } else {
+            a.cmp(&b)
+        }
+    }
+
+    /// Whether edges `a` and `b` are no more than `epsilon` apart wherever
+    /// they both cross the sweep line. Being straight, they are furthest
+    /// apart at one end or the other of that span.
+    fn coincident(&self, a: usize, b: usize) -> bool {
+        let (a, b) = (&self.edges[a], &self.edges[b]);
+        let lowest = if a.lower.y < b.lower.y {
+            b.lower.y
+        } else {
+            a.lower.y
+        };
+        let highest = if a.upper.y < b.upper.y {
+            a.upper.y
+        } else {
+            b.upper.y
+        };
+        lowest < highest
+            && self.cmp_at(a.at(lowest), b.at(lowest)) == Ordering::Equal
+            && self.cmp_at(a.at(highest), b.at(highest)) == Ordering::Equal
+    }
+
+    /// Position of active edge `edge` on the sweep line just above or below
+    /// y = `y`, found by binary search, or failing that linearly.
+    fn position(&self, edge: usize, y: T, above: bool) -> Option<usize> {
+        let start = self
+            .active
+            .partition_point(|other| self.cmp_edges(*other, edge, y, above) == Ordering::Less);
+        self.active[start..]
+            .iter()
+            .take_while(|other| self.cmp_edges(**other, edge, y, above) == Ordering::Equal)
+            .position(|other| *other == edge)
+            .map(|offset| start + offset)
+            .or_else(|| self.active.iter().position(|other| *other == edge))
+    }
+
+    /// Whether the inside of the ring of `edge` is to the edge's right.
+    fn inside_right(&self, edge: usize) -> bool {
+        let edge = &self.edges[edge];
+        (edge.direction == UpDown::Up) == self.clockwise[edge.ring]
+    }
+
+    /// The innermost ring around the points just left of `edge`.
+    fn left_of(&self, edge: usize) -> Option<usize> {
+        let ring = self.edges[edge].ring;
+        if self.inside_right(edge) {
+            self.parents[ring]
+        } else {
+            Some(ring)
+        }
+    }
+
+    /// The innermost ring around the points just right of `edge`.
+    fn right_of(&self, edge: usize) -> Option<usize> {
+        let ring = self.edges[edge].ring;
+        if self.inside_right(edge) {
+            Some(ring)
+        } else {
+            self.parents[ring]
+        }
+    }
+
+    /// Classify `ring`, first reached at y = `y`, whose leftmost edge is at
+    /// `position` along the sweep line. Its parent is the innermost ring
+    /// around the points just left of that edge.
+    fn classify(
+        &mut self,
+        ring: usize,
+        position: usize,
+        y: T,
+        orientation: OrientationPolicy,
+    ) -> CollateResult<(), T> {
+        let edge = self.active[position];
+        let parent = position
+            .checked_sub(1)
+            .and_then(|left| self.right_of(self.active[left]));
+        self.parents[ring] = parent;
+
+        if orientation == OrientationPolicy::Ignore {
+            return Ok(());
+        }
+        let x = self.edges[edge].x(y);
+        match (self.clockwise[ring], parent) {
+            (true, Some(parent)) if self.clockwise[parent] => Err(CollateError::OutlineInOutline {
+                ring,
+                neighbour: parent,
+                sweep: y,
+                x,
+            }),
+            (false, None) => Err(CollateError::HoleWithoutOutline {
+                ring,
+                neighbour: None,
+                sweep: y,
+                x,
+            }),
+            (false, Some(parent)) if !self.clockwise[parent] => Err(CollateError::OutlineIsHole {
+                ring,
+                neighbour: parent,
+                sweep: y,
+                x,
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Classify `ring`, which encloses nothing, from its lowest vertex
+    /// `point` at y = `y`. Its parent is the innermost ring around the points
+    /// just above `point`, and it is wound as an exterior or a hole to suit.
+    fn classify_point(&mut self, ring: usize, point: Coord<T>, y: T) {
+        let x = Fraction {
+            numerator: point.x.widen(),
+            denominator: T::one().widen(),
+        };
+        let position = self.active.partition_point(|edge| {
+            let edge = &self.edges[*edge];
+            match self.cmp_at(edge.at(y), x) {
+                Ordering::Equal => edge.slope().numerator < T::zero().widen(),
+                at => at == Ordering::Less,
+            }
+        });
+        let parent = position
+            .checked_sub(1)
+            .and_then(|left| self.right_of(self.active[left]));
+        self.parents[ring] = parent;
+        self.clockwise[ring] = parent.is_none_or(|parent| !self.clockwise[parent]);
+    }
+
+    /// Check the edges at `left` and `left + 1` along the sweep line, where
+    /// `None` or a position past the end stands for no edge. Edges of
+    /// different rings must not cross, unless they coincide within `epsilon`,
+    /// and must agree on the innermost ring around the points between them;
+    /// if they don't, their rings overlap.
+    fn check_pair(&self, left: Option<usize>, y: T) -> CollateResult<(), T> {
+        let a = left.map(|left| self.active[left]);
+        let b = self.active.get(left.map_or(0, |left| left + 1)).copied();
+
+        if let (Some(a), Some(b)) = (a, b) {
+            let (edge_a, edge_b) = (&self.edges[a], &self.edges[b]);
+            if segments_cross(edge_a.lower, edge_a.upper, edge_b.lower, edge_b.upper)
+                && !self.coincident(a, b)
+            {
+                return Err(self.rings_cross(edge_a.ring, edge_b.ring, b, y));
+            }
+        }
+
+        let between = a.and_then(|a| self.right_of(a));
+        if between == b.and_then(|b| self.left_of(b)) {
+            return Ok(());
+        }
+        let rings = match (a, b) {
+            (Some(a), Some(b)) => (self.edges[a].ring, self.edges[b].ring, b),
+            (Some(a), None) => (self.edges[a].ring, between.unwrap_or_default(), a),
+            (None, Some(b)) => (self.edges[b].ring, self.left_of(b).unwrap_or_default(), b),
+            (None, None) => return Ok(()),
+        };
+        Err(self.rings_cross(rings.0, rings.1, rings.2, y))
+    }
+
+    /// The error for rings `one` and `two` crossing at `edge`, which is a
+    /// self-intersection if they are the same ring.
+    fn rings_cross(&self, one: usize, two: usize, edge: usize, y: T) -> CollateError<T> {
+        let x = self.edges[edge].x(y);
+        if one == two {
+            return CollateError::SelfIntersection {
+                ring: one,
+                sweep: y,
+                x,
+            };
+        }
+        CollateError::RingsCross {
+            ring: one.min(two),
+            neighbour: one.max(two),
+            sweep: y,
+            x,
+        }
+    }
+}
+
+/// Sort `items` by `cmp`, a merge sort that unlike `sort_by` can't panic when
+/// `cmp` is not a total order, as comparisons within `epsilon` aren't.
+fn merge_sort_by(mut items: Vec<usize>, cmp: &impl Fn(usize, usize) -> Ordering) -> Vec<usize> {
+    if items.len() < 2 {
+        return items;
+    }
+    let right = merge_sort_by(items.split_off(items.len() / 2), cmp);
+    let left = merge_sort_by(items, cmp);
+    let mut sorted = Vec::with_capacity(left.len() + right.len());
+    let (mut left, mut right) = (left.into_iter().peekable(), right.into_iter().peekable());
+    while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+        if cmp(*b, *a) == Ordering::Less {
+            sorted.extend(right.next());
+        } else {
+            sorted.extend(left.next());
+        }
+    }
+    sorted.extend(left);
+    sorted.extend(right);
+    sorted
+}
+
+/// Sweep a line up across `polys`, ignoring the rings in `skipped`, and work
+/// out which ring directly encloses each of the others.
+///
+/// The sweep stops at every y a vertex lies at, and looks at the line just
+/// above it. There it removes the edges ending at that y from the edges
+/// crossing the line, kept in order along it, and adds the edges starting
+/// there. Each ring is classified as soon as the sweep reaches its lowest
+/// vertex, from the edge just left of it. Every pair of edges that becomes
+/// adjacent is checked for crossing rings.
+///
+/// Edges are found along the line by binary search, so the sweep makes
+/// O(n log n) comparisons for n vertices. The edges crossing the line are
+/// kept in a `Vec`, which the edges ending and starting at each stop are
+/// removed from and merged into in one pass each, so the sweep also takes
+/// O(s k) time for s stops, where k is the most edges crossing any one line:
+/// O(n²) at worst, such as for many long thin rings side by side, each
+/// starting a little higher than the last. With `epsilon`, crossings within
+/// it compare equal without that being transitive, so an edge the binary
+/// search misses is looked for linearly, which is O(k) again.
+///
+/// Vertices and crossings no more than `epsilon` apart are treated as
+/// coincident. With `OrientationPolicy::Enforce`, exteriors must be
+/// clockwise and holes counter-clockwise.
+pub(crate) fn sweep_nesting<T>(
+    polys: &MultiLineString<T>,
+    skipped: &HashSet<usize>,
+    orientation: OrientationPolicy,
+    epsilon: f64,
+) -> CollateResult<Nesting, T>
+where
+    T: CoordNum + Numeric,
+{
+    let snapped;
+    let polys = if epsilon > 0.0 {
+        snapped = snap_vertices(polys, epsilon);
+        &snapped
+    } else {
+        polys
+    };
+
+    let cmp = |a: &T, b: &T| a.partial_cmp(b).unwrap_or(Ordering::Equal);
+    let cmp_coords = |a: &Coord<T>, b: &Coord<T>| cmp(&a.y, &b.y).then(cmp(&a.x, &b.x));
+
+    // Rings that enclose nothing have no edges to sweep, and are instead
+    // placed by their lowest vertex.
+    let mut edges: Vec<SweepEdge<T>> = Vec::new();
+    let mut points: Vec<(usize, Coord<T>)> = Vec::new();
+    let mut areas = vec![0.0; polys.0.len()];
+    for (ring, ls) in polys.0.iter().enumerate() {
+        if skipped.contains(&ring) {
+            continue;
+        }
+        let stripped = LineString(strip_spikes(ls));
+        if stripped.0.len() >= 3 {
+            areas[ring] = signed_area(&stripped).abs();
+            edges.extend(ring_lines(&stripped).filter_map(|line| SweepEdge::new(line, ring)));
+        } else if let Some(lowest) = ls.0.iter().copied().min_by(cmp_coords) {
+            points.push((ring, lowest));
+        }
+    }
+    points.sort_by(|a, b| cmp_coords(&a.1, &b.1));
+
+    let mut starts: Vec<usize> = (0..edges.len()).collect();
+    starts.sort_by(|a, b| {
+        let (a, b) = (&edges[*a], &edges[*b]);
+        cmp(&a.lower.y, &b.lower.y)
+            .then(cmp(&a.lower.x, &b.lower.x))
+            .then(a.slope().cmp(&b.slope()))
+            .then(a.ring.cmp(&b.ring))
+    });
+    let mut ends: Vec<usize> = (0..edges.len()).collect();
+    ends.sort_by(|a, b| cmp(&edges[*a].upper.y, &edges[*b].upper.y));
+
+    // A ring's winding is that of the leftmost edge leaving its lowest,
+    // leftmost vertex, which comes first in `starts`: clockwise if it runs
+    // up.
+    let rings = polys.0.len();
+    let mut clockwise = vec![None; rings];
+    for edge in starts.iter().map(|e| &edges[*e]) {
+        clockwise[edge.ring].get_or_insert(edge.direction == UpDown::Up);
+    }
+
+    let mut sweep = Sweep {
+        edges,
+        active: Vec::new(),
+        epsilon,
+        parents: vec![None; rings],
+        clockwise: clockwise
+            .into_iter()
+            .map(Option::unwrap_or_default)
+            .collect(),
+        areas,
+        reached: vec![false; rings],
+    };
+    let (mut starts, mut ends) = (starts.into_iter().peekable(), ends.into_iter().peekable());
+    let mut points = points.into_iter().peekable();
+
+    loop {
+        let next_start = starts.peek().map(|e| sweep.edges[*e].lower.y);
+        let next_end = ends.peek().map(|e| sweep.edges[*e].upper.y);
+        let next_point = points.peek().map(|(_, point)| point.y);
+        let Some(y) = [next_start, next_end, next_point]
+            .into_iter()
+            .flatten()
+            .min_by(cmp)
+        else {
+            break;
+        };
+
+        // Remove the edges ending here in one pass, noting the edge left of
+        // each run of removed edges, whose right-hand neighbour changes.
+        let mut removed: Vec<usize> =
+            std::iter::from_fn(|| ends.next_if(|e| sweep.edges[*e].upper.y == y))
+                .filter_map(|edge| sweep.position(edge, y, false))
+                .collect();
+        removed.sort_unstable();
+        let mut removed = removed.into_iter().peekable();
+        let mut anchors: Vec<Option<usize>> = Vec::new();
+        let (mut position, mut left) = (0, None);
+        sweep.active.retain(|edge| {
+            let remove = removed.next_if_eq(&position).is_some();
+            position += 1;
+            if !remove {
+                left = Some(*edge);
+            } else if anchors.last() != Some(&left) {
+                anchors.push(left);
+            }
+            !remove
+        });
+
+        // Merge the edges starting here, in order, into the active edges in
+        // one pass, noting where each lands.
+        let inserted = merge_sort_by(
+            std::iter::from_fn(|| starts.next_if(|e| sweep.edges[*e].lower.y == y)).collect(),
+            &|a, b| sweep.cmp_edges(a, b, y, true),
+        );
+        let mut positions: Vec<usize> = Vec::with_capacity(inserted.len());
+        let mut active = Vec::with_capacity(sweep.active.len() + inserted.len());
+        let mut from = 0;
+        for edge in &inserted {
+            let before = from
+                + sweep.active[from..].partition_point(|other| {
+                    sweep.cmp_edges(*other, *edge, y, true) != Ordering::Greater
+                });
+            active.extend_from_slice(&sweep.active[from..before]);
+            from = before;
+            positions.push(active.len());
+            active.push(*edge);
+        }
+        active.extend_from_slice(&sweep.active[from..]);
+        sweep.active = active;
+
+        // Positions are in order, so the first edge of each ring reached is
+        // its leftmost.
+        let mut seen = HashSet::new();
+        let reached: Vec<(usize, usize)> = positions
+            .iter()
+            .map(|position| (sweep.edges[sweep.active[*position]].ring, *position))
+            .filter(|(ring, _)| !sweep.reached[*ring] && seen.insert(*ring))
+            .collect();
+        for (ring, leftmost) in reached {
+            sweep.reached[ring] = true;
+            sweep.classify(ring, leftmost, y, orientation)?;
+        }
+        while let Some((ring, point)) = points.next_if(|(_, point)| point.y == y) {
+            sweep.reached[ring] = true;
+            sweep.classify_point(ring, point, y);
+        }
+
+        for position in positions {
+            sweep.check_pair(position.checked_sub(1), y)?;
+            sweep.check_pair(Some(position), y)?;
+        }
+        for anchor in anchors {
+            let left = match anchor {
+                Some(edge) => match sweep.position(edge, y, true) {
+                    Some(position) => Some(position),
+                    None => continue,
+                },
+                None => None,
+            };
+            sweep.check_pair(left, y)?;
+        }
+    }
+
+    Ok(Nesting {
+        parents: sweep.parents,
+        reached: sweep.reached,
+    })
+}
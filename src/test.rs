@@ -1,12 +1,16 @@
 #[cfg(test)]
 mod test {
+    use crate::chain::*;
     use crate::collate::*;
-    use geo::prelude::Translate;
-    use geo_types::{
-        Coordinate, CoordinateType, LineString, MultiLineString, MultiPolygon, Polygon,
-    };
+    use crate::fill::*;
+    use crate::numeric::Numeric;
+    use crate::quantize::*;
+    use crate::tree::*;
+    use geo::prelude::{Area, Translate};
+    use geo::winding_order::Winding as _;
+    use geo_types::{Coord, CoordNum, Line, LineString, MultiLineString, MultiPolygon, Polygon};
 
-    fn polygons_equivalent<T: CoordinateType>(one: &Polygon<T>, two: &Polygon<T>) -> bool {
+    fn polygons_equivalent<T: CoordNum>(one: &Polygon<T>, two: &Polygon<T>) -> bool {
         if one.exterior() != two.exterior() {
             return false;
         }
@@ -29,10 +33,10 @@ mod test {
             }
         }
 
-        return true;
+        true
     }
 
-    fn multi_polygons_equivalent<T: CoordinateType>(
+    fn multi_polygons_equivalent<T: CoordNum>(
         one: &MultiPolygon<T>,
         two: &MultiPolygon<T>,
     ) -> bool {
@@ -55,7 +59,7 @@ mod test {
             }
         }
 
-        return true;
+        true
     }
 
     #[test]
@@ -482,3515 +486,3515 @@ mod test {
     #[test]
     fn octopus_layer() {
         let layer = MultiLineString(vec![LineString(vec![
-            Coordinate {
+            Coord {
                 x: -0.06148776779719839,
                 y: 21.08726538461537,
             },
-            Coordinate {
+            Coord {
                 x: 0.010888609340072575,
                 y: 20.83853598277212,
             },
-            Coordinate {
+            Coord {
                 x: 0.01352752935146273,
                 y: 20.83109032889585,
             },
-            Coordinate {
+            Coord {
                 x: 0.7007118528744496,
                 y: 20.867004577589256,
             },
-            Coordinate {
+            Coord {
                 x: 1.7651915109257719,
                 y: 20.774226781933663,
             },
-            Coordinate {
+            Coord {
                 x: 3.073542794187464,
                 y: 20.60272918136909,
             },
-            Coordinate {
+            Coord {
                 x: 3.8929185744680854,
                 y: 20.352614361702127,
             },
-            Coordinate {
+            Coord {
                 x: 4.49005372582205,
                 y: 20.17874095744681,
             },
-            Coordinate {
+            Coord {
                 x: 5.2982631296572285,
                 y: 19.7519105296343,
             },
-            Coordinate {
+            Coord {
                 x: 6.200597303679927,
                 y: 19.369241803278687,
             },
-            Coordinate {
+            Coord {
                 x: 6.812109696610124,
                 y: 18.711550355977455,
             },
-            Coordinate {
+            Coord {
                 x: 7.612515777891643,
                 y: 18.042605324829427,
             },
-            Coordinate {
+            Coord {
                 x: 8.193687496580027,
                 y: 17.00482975376197,
             },
-            Coordinate {
+            Coord {
                 x: 8.913517761348091,
                 y: 15.498490355677156,
             },
-            Coordinate {
+            Coord {
                 x: 9.172079470141991,
                 y: 14.402422961844634,
             },
-            Coordinate {
+            Coord {
                 x: 9.930007194544956,
                 y: 12.595277065605272,
             },
-            Coordinate {
+            Coord {
                 x: 10.138493225240707,
                 y: 11.589890444044403,
             },
-            Coordinate {
+            Coord {
                 x: 10.472352601178299,
                 y: 10.554994374437443,
             },
-            Coordinate {
+            Coord {
                 x: 12.340197833783378,
                 y: 9.52010015926138,
             },
-            Coordinate {
+            Coord {
                 x: 12.969037033492823,
                 y: 8.966239602870813,
             },
-            Coordinate {
+            Coord {
                 x: 14.187640299959465,
                 y: 8.939444052806131,
             },
-            Coordinate {
+            Coord {
                 x: 15.286206615322254,
                 y: 8.762651024836938,
             },
-            Coordinate {
+            Coord {
                 x: 16.55244514056225,
                 y: 8.853177662285507,
             },
-            Coordinate {
+            Coord {
                 x: 17.236801365461847,
                 y: 8.895279670317635,
             },
-            Coordinate {
+            Coord {
                 x: 18.7936282626539,
                 y: 9.148506849023754,
             },
-            Coordinate {
+            Coord {
                 x: 19.012936060191517,
                 y: 9.224552410396717,
             },
-            Coordinate {
+            Coord {
                 x: 19.25887409028728,
                 y: 9.39700782862828,
             },
-            Coordinate {
+            Coord {
                 x: 20.426542608695648,
                 y: 10.175913671936756,
             },
-            Coordinate {
+            Coord {
                 x: 21.00605789029536,
                 y: 10.538736660529343,
             },
-            Coordinate {
+            Coord {
                 x: 21.114733161520483,
                 y: 10.608078822733424,
             },
-            Coordinate {
+            Coord {
                 x: 21.989499144542773,
                 y: 12.292867050147493,
             },
-            Coordinate {
+            Coord {
                 x: 22.186401318848866,
                 y: 12.702478860936408,
             },
-            Coordinate {
+            Coord {
                 x: 22.15583181126332,
                 y: 13.518502511415525,
             },
-            Coordinate {
+            Coord {
                 x: 22.03867094887179,
                 y: 16.13062137733142,
             },
-            Coordinate {
+            Coord {
                 x: 21.77238904,
                 y: 17.063638,
             },
-            Coordinate {
+            Coord {
                 x: 21.188697432744043,
                 y: 19.157146848578016,
             },
-            Coordinate {
+            Coord {
                 x: 20.929558710717163,
                 y: 20.23260777598711,
             },
-            Coordinate {
+            Coord {
                 x: 20.49831397324941,
                 y: 22.25549319433517,
             },
-            Coordinate {
+            Coord {
                 x: 20.477551548117155,
                 y: 23.447674351464435,
             },
-            Coordinate {
+            Coord {
                 x: 20.455800901639346,
                 y: 25.38585393442623,
             },
-            Coordinate {
+            Coord {
                 x: 20.82074742857143,
                 y: 26.474966408163265,
             },
-            Coordinate {
+            Coord {
                 x: 21.032764362730294,
                 y: 27.166926910299004,
             },
-            Coordinate {
+            Coord {
                 x: 21.606130629139074,
                 y: 28.170946688741722,
             },
-            Coordinate {
+            Coord {
                 x: 21.97977507346189,
                 y: 28.83267777777778,
             },
-            Coordinate {
+            Coord {
                 x: 23.076982200230148,
                 y: 29.828628396624474,
             },
-            Coordinate {
+            Coord {
                 x: 23.795319546247818,
                 y: 30.472989965095987,
             },
-            Coordinate {
+            Coord {
                 x: 25.01754561018437,
                 y: 31.07793937664618,
             },
-            Coordinate {
+            Coord {
                 x: 25.8657391598916,
                 y: 31.492427235772357,
             },
-            Coordinate {
+            Coord {
                 x: 26.925092752962627,
                 y: 31.5383112579763,
             },
-            Coordinate {
+            Coord {
                 x: 27.687997134935305,
                 y: 31.581126340110906,
             },
-            Coordinate {
+            Coord {
                 x: 28.657738030888034,
                 y: 31.405857335907335,
             },
-            Coordinate {
+            Coord {
                 x: 29.888076387536515,
                 y: 31.19783169425511,
             },
-            Coordinate {
+            Coord {
                 x: 30.77959560669456,
                 y: 30.667062343096234,
             },
-            Coordinate {
+            Coord {
                 x: 31.824922842105263,
                 y: 30.022619157894738,
             },
-            Coordinate {
+            Coord {
                 x: 32.384647458563535,
                 y: 29.308962707182317,
             },
-            Coordinate {
+            Coord {
                 x: 32.930236932070144,
                 y: 28.62972915742794,
             },
-            Coordinate {
+            Coord {
                 x: 33.40243915775401,
                 y: 27.417105882352942,
             },
-            Coordinate {
+            Coord {
                 x: 33.78587905982906,
                 y: 26.456192735042737,
             },
-            Coordinate {
+            Coord {
                 x: 33.804014294330514,
                 y: 25.554209589867313,
             },
-            Coordinate {
+            Coord {
                 x: 33.802290403903164,
                 y: 24.424874048913043,
             },
-            Coordinate {
+            Coord {
                 x: 33.55289204951857,
                 y: 23.805794841815683,
             },
-            Coordinate {
+            Coord {
                 x: 33.13353828571428,
                 y: 22.869603714285713,
             },
-            Coordinate {
+            Coord {
                 x: 31.844447168271582,
                 y: 21.56045053859964,
             },
-            Coordinate {
+            Coord {
                 x: 31.589993333333332,
                 y: 21.265352500000002,
             },
-            Coordinate {
+            Coord {
                 x: 29.89847020202021,
                 y: 20.314774242424246,
             },
-            Coordinate {
+            Coord {
                 x: 29.776206603773584,
                 y: 20.24805471698113,
             },
-            Coordinate {
+            Coord {
                 x: 29.683012025316447,
                 y: 20.240690506329113,
             },
-            Coordinate {
+            Coord {
                 x: 27.497459345794393,
                 y: 20.127992056074767,
             },
-            Coordinate {
+            Coord {
                 x: 26.892066022099442,
                 y: 20.369673756906078,
             },
-            Coordinate {
+            Coord {
                 x: 25.738433644859814,
                 y: 20.8454285046729,
             },
-            Coordinate {
+            Coord {
                 x: 25.502154292671616,
                 y: 21.09019107142857,
             },
-            Coordinate {
+            Coord {
                 x: 25.154493258426967,
                 y: 21.451698876404492,
             },
-            Coordinate {
+            Coord {
                 x: 25.56378841724942,
                 y: 21.153132307692307,
             },
-            Coordinate {
+            Coord {
                 x: 25.827658018867925,
                 y: 21.004933490566035,
             },
-            Coordinate {
+            Coord {
                 x: 26.50416591836735,
                 y: 20.757312857142853,
             },
-            Coordinate {
+            Coord {
                 x: 27.576205458813487,
                 y: 20.389938262910796,
             },
-            Coordinate {
+            Coord {
                 x: 28.081310122574056,
                 y: 20.45590767790262,
             },
-            Coordinate {
+            Coord {
                 x: 29.60730164319249,
                 y: 20.763275352112675,
             },
-            Coordinate {
+            Coord {
                 x: 29.648015175718854,
                 y: 20.79281517571885,
             },
-            Coordinate {
+            Coord {
                 x: 29.730729591836745,
                 y: 20.85393265306123,
             },
-            Coordinate {
+            Coord {
                 x: 30.96186148325359,
                 y: 21.70242033492823,
             },
-            Coordinate {
+            Coord {
                 x: 31.18817417218543,
                 y: 21.822496854304635,
             },
-            Coordinate {
+            Coord {
                 x: 31.365036702127657,
                 y: 22.035047872340424,
             },
-            Coordinate {
+            Coord {
                 x: 32.39781927272727,
                 y: 23.247991276595744,
             },
-            Coordinate {
+            Coord {
                 x: 32.50551139664804,
                 y: 23.599085754189943,
             },
-            Coordinate {
+            Coord {
                 x: 32.822329282814614,
                 y: 24.42639303112314,
             },
-            Coordinate {
+            Coord {
                 x: 32.820663370744484,
                 y: 25.020313511659808,
             },
-            Coordinate {
+            Coord {
                 x: 32.77088164301552,
                 y: 26.185701463414635,
             },
-            Coordinate {
+            Coord {
                 x: 32.50398729726715,
                 y: 26.902169754601225,
             },
-            Coordinate {
+            Coord {
                 x: 32.04126911111111,
                 y: 27.987764555555557,
             },
-            Coordinate {
+            Coord {
                 x: 31.647999944506104,
                 y: 28.46753923418424,
             },
-            Coordinate {
+            Coord {
                 x: 31.127455396825397,
                 y: 29.0939826984127,
             },
-            Coordinate {
+            Coord {
                 x: 30.44923255813954,
                 y: 29.55751353065539,
             },
-            Coordinate {
+            Coord {
                 x: 29.682444412264235,
                 y: 29.995819911504427,
             },
-            Coordinate {
+            Coord {
                 x: 28.69701233880266,
                 y: 30.2022816097561,
             },
-            Coordinate {
+            Coord {
                 x: 27.790147198879552,
                 y: 30.359385294117647,
             },
-            Coordinate {
+            Coord {
                 x: 26.961438682745825,
                 y: 30.306906586270873,
             },
-            Coordinate {
+            Coord {
                 x: 26.2434147166362,
                 y: 30.25297851919561,
             },
-            Coordinate {
+            Coord {
                 x: 25.37167597876576,
                 y: 29.835370437956204,
             },
-            Coordinate {
+            Coord {
                 x: 24.604307272727272,
                 y: 29.44911819787986,
             },
-            Coordinate {
+            Coord {
                 x: 23.937540303030303,
                 y: 28.77823205128205,
             },
-            Coordinate {
+            Coord {
                 x: 23.344010996369814,
                 y: 28.19831873406967,
             },
-            Coordinate {
+            Coord {
                 x: 22.910568189581554,
                 y: 27.39731293766012,
             },
-            Coordinate {
+            Coord {
                 x: 22.587128661087867,
                 y: 26.794778870292888,
             },
-            Coordinate {
+            Coord {
                 x: 22.38626590985485,
                 y: 25.92412142857143,
             },
-            Coordinate {
+            Coord {
                 x: 22.229949829545454,
                 y: 25.29813125,
             },
-            Coordinate {
+            Coord {
                 x: 22.331526400274786,
                 y: 23.55394084802687,
             },
-            Coordinate {
+            Coord {
                 x: 22.396756209856232,
                 y: 22.45136526357199,
             },
-            Coordinate {
+            Coord {
                 x: 22.718026916167663,
                 y: 21.438030538922153,
             },
-            Coordinate {
+            Coord {
                 x: 23.399811080025117,
                 y: 19.482226362240983,
             },
-            Coordinate {
+            Coord {
                 x: 23.740263903080393,
                 y: 18.613442011019284,
             },
-            Coordinate {
+            Coord {
                 x: 24.60390506437768,
                 y: 16.474625250357654,
             },
-            Coordinate {
+            Coord {
                 x: 24.92816869801316,
                 y: 12.659147962052003,
             },
-            Coordinate {
+            Coord {
                 x: 24.173935210402608,
                 y: 9.956412491091061,
             },
-            Coordinate {
+            Coord {
                 x: 24.13456312396694,
                 y: 9.8310492446281,
             },
-            Coordinate {
+            Coord {
                 x: 23.578766363636362,
                 y: 9.208967285714282,
             },
-            Coordinate {
+            Coord {
                 x: 21.760740188045666,
                 y: 7.283062592832285,
             },
-            Coordinate {
+            Coord {
                 x: 21.313857405824532,
                 y: 6.828671995115697,
             },
-            Coordinate {
+            Coord {
                 x: 19.744859045792914,
                 y: 5.8500657829912015,
             },
-            Coordinate {
+            Coord {
                 x: 18.805989032258065,
                 y: 5.2491379914279275,
             },
-            Coordinate {
+            Coord {
                 x: 17.470490517023958,
                 y: 4.467765943979518,
             },
-            Coordinate {
+            Coord {
                 x: 16.31292654056326,
                 y: 3.7327158415300543,
             },
-            Coordinate {
+            Coord {
                 x: 14.987631483474251,
                 y: 3.0072801050241074,
             },
-            Coordinate {
+            Coord {
                 x: 13.63669804765565,
                 y: 2.156483744811683,
             },
-            Coordinate {
+            Coord {
                 x: 13.273114880860875,
                 y: 1.9496852820906998,
             },
-            Coordinate {
+            Coord {
                 x: 12.252034990964944,
                 y: 1.3257203411637153,
             },
-            Coordinate {
+            Coord {
                 x: 12.061943194795807,
                 y: 1.0096926760291751,
             },
-            Coordinate {
+            Coord {
                 x: 11.434426101694914,
                 y: 0.548328214329738,
             },
-            Coordinate {
+            Coord {
                 x: 12.049012598225602,
                 y: 0.09493810424780114,
             },
-            Coordinate {
+            Coord {
                 x: 12.238187249683143,
                 y: -0.10339011314667595,
             },
-            Coordinate {
+            Coord {
                 x: 14.16732037735849,
                 y: -0.4584178799313895,
             },
-            Coordinate {
+            Coord {
                 x: 14.687316603773585,
                 y: -0.6179386974271013,
             },
-            Coordinate {
+            Coord {
                 x: 17.763016335282654,
                 y: -0.5705853020142951,
             },
-            Coordinate {
+            Coord {
                 x: 18.43331769980507,
                 y: -0.5793399651899109,
             },
-            Coordinate {
+            Coord {
                 x: 20.956411442406058,
                 y: -0.21543464658664663,
             },
-            Coordinate {
+            Coord {
                 x: 21.075134246061516,
                 y: -0.19698205843960997,
             },
-            Coordinate {
+            Coord {
                 x: 23.040759272727264,
                 y: 0.4511722563636331,
             },
-            Coordinate {
+            Coord {
                 x: 23.19587550387597,
                 y: 0.49963446779422105,
             },
-            Coordinate {
+            Coord {
                 x: 23.205687111111114,
                 y: 0.5039109645656565,
             },
-            Coordinate {
+            Coord {
                 x: 24.810370080744754,
                 y: 1.2419652641873276,
             },
-            Coordinate {
+            Coord {
                 x: 24.936022249431144,
                 y: 1.341469282479142,
             },
-            Coordinate {
+            Coord {
                 x: 26.290122921348313,
                 y: 2.5278789662921346,
             },
-            Coordinate {
+            Coord {
                 x: 27.724430976658475,
                 y: 4.114721394963144,
             },
-            Coordinate {
+            Coord {
                 x: 27.945226017615603,
                 y: 4.351800262661214,
             },
-            Coordinate {
+            Coord {
                 x: 29.50286204625763,
                 y: 6.00793946321876,
             },
-            Coordinate {
+            Coord {
                 x: 29.70316395348837,
                 y: 6.217383340380549,
             },
-            Coordinate {
+            Coord {
                 x: 31.6052374251497,
                 y: 7.760636314643439,
             },
-            Coordinate {
+            Coord {
                 x: 31.772148468786806,
                 y: 7.8958668189313626,
             },
-            Coordinate {
+            Coord {
                 x: 33.4417129827832,
                 y: 8.82275788814563,
             },
-            Coordinate {
+            Coord {
                 x: 33.53797617901101,
                 y: 8.877894686460808,
             },
-            Coordinate {
+            Coord {
                 x: 35.9828087890053,
                 y: 9.69223429582066,
             },
-            Coordinate {
+            Coord {
                 x: 36.01735785202864,
                 y: 9.704519049251465,
             },
-            Coordinate {
+            Coord {
                 x: 36.93926000000002,
                 y: 9.937350787878794,
             },
-            Coordinate {
+            Coord {
                 x: 37.54194852459016,
                 y: 10.085045628415301,
             },
-            Coordinate {
+            Coord {
                 x: 37.559828787878786,
                 y: 10.084569672131149,
             },
-            Coordinate {
+            Coord {
                 x: 40.24268772204806,
                 y: 10.03719827586207,
             },
-            Coordinate {
+            Coord {
                 x: 40.28153394636015,
                 y: 10.027840495646117,
             },
-            Coordinate {
+            Coord {
                 x: 41.77933794676806,
                 y: 9.681891956100932,
             },
-            Coordinate {
+            Coord {
                 x: 43.401696170212766,
                 y: 8.867055127659574,
             },
-            Coordinate {
+            Coord {
                 x: 43.45783201160542,
                 y: 8.838263605415861,
             },
-            Coordinate {
+            Coord {
                 x: 44.73855283442793,
                 y: 7.794885079847909,
             },
-            Coordinate {
+            Coord {
                 x: 44.78137558935361,
                 y: 7.759170283097131,
             },
-            Coordinate {
+            Coord {
                 x: 46.00845375,
                 y: 5.556670755681818,
             },
-            Coordinate {
+            Coord {
                 x: 46.09560285714285,
                 y: 5.201223428571447,
             },
-            Coordinate {
+            Coord {
                 x: 46.409446776859504,
                 y: 3.8459839559228652,
             },
-            Coordinate {
+            Coord {
                 x: 46.40417259720063,
                 y: 3.774479371695178,
             },
-            Coordinate {
+            Coord {
                 x: 46.32473324675325,
                 y: 2.046916139941691,
             },
-            Coordinate {
+            Coord {
                 x: 46.26792260504202,
                 y: 1.8490084705882346,
             },
-            Coordinate {
+            Coord {
                 x: 45.84123174336533,
                 y: 0.2830372484472051,
             },
-            Coordinate {
+            Coord {
                 x: 45.6397089010989,
                 y: -0.04822107692307773,
             },
-            Coordinate {
+            Coord {
                 x: 44.895276033057854,
                 y: -1.290085685950413,
             },
-            Coordinate {
+            Coord {
                 x: 44.452212929292926,
                 y: -1.7099684040404046,
             },
-            Coordinate {
+            Coord {
                 x: 43.63621501284934,
                 y: -2.4977271519434625,
             },
-            Coordinate {
+            Coord {
                 x: 42.71382027164686,
                 y: -2.8895553005093375,
             },
-            Coordinate {
+            Coord {
                 x: 42.021440099673484,
                 y: -3.146603387523629,
             },
-            Coordinate {
+            Coord {
                 x: 40.313682535211264,
                 y: -3.1216282816901404,
             },
-            Coordinate {
+            Coord {
                 x: 39.76690159929008,
                 y: -3.1409050498915394,
             },
-            Coordinate {
+            Coord {
                 x: 38.41420016393443,
                 y: -2.7361684918032796,
             },
-            Coordinate {
+            Coord {
                 x: 37.78954163265306,
                 y: -2.521901061224489,
             },
-            Coordinate {
+            Coord {
                 x: 37.004571937229436,
                 y: -1.8467063333333287,
             },
-            Coordinate {
+            Coord {
                 x: 36.11590434275185,
                 y: -1.0443628648648646,
             },
-            Coordinate {
+            Coord {
                 x: 35.4777408,
                 y: 0.37502871701818696,
             },
-            Coordinate {
+            Coord {
                 x: 35.348282448979596,
                 y: 0.6730294510204083,
             },
-            Coordinate {
+            Coord {
                 x: 35.32911,
                 y: 0.8354519136363611,
             },
-            Coordinate {
+            Coord {
                 x: 35.179274059775835,
                 y: 1.98016295890411,
             },
-            Coordinate {
+            Coord {
                 x: 35.1833274131016,
                 y: 1.9995644999999997,
             },
-            Coordinate {
+            Coord {
                 x: 35.1889990976431,
                 y: 2.0030520208754203,
             },
-            Coordinate {
+            Coord {
                 x: 35.194963643147894,
                 y: 1.9992811044776118,
             },
-            Coordinate {
+            Coord {
                 x: 35.20239685415304,
                 y: 1.9796570359712236,
             },
-            Coordinate {
+            Coord {
                 x: 35.368704966442955,
                 y: 0.8430525854179354,
             },
-            Coordinate {
+            Coord {
                 x: 35.394885088293,
                 y: 0.6872540276651405,
             },
-            Coordinate {
+            Coord {
                 x: 35.99148641975309,
                 y: -0.5391715387766589,
             },
-            Coordinate {
+            Coord {
                 x: 36.22007639657444,
                 y: -0.9858103509222663,
             },
-            Coordinate {
+            Coord {
                 x: 37.053937611940306,
                 y: -1.5795664613297182,
             },
-            Coordinate {
+            Coord {
                 x: 37.97409768115942,
                 y: -2.1419871304347833,
             },
-            Coordinate {
+            Coord {
                 x: 38.2161711969112,
                 y: -2.1659966486486493,
             },
-            Coordinate {
+            Coord {
                 x: 38.47635833333334,
                 y: -2.1883396666666677,
             },
-            Coordinate {
+            Coord {
                 x: 39.5894663670412,
                 y: -2.3286056779026225,
             },
-            Coordinate {
+            Coord {
                 x: 39.869493057851244,
                 y: -2.395053371900827,
             },
-            Coordinate {
+            Coord {
                 x: 40.27501322800194,
                 y: -2.3360116363636374,
             },
-            Coordinate {
+            Coord {
                 x: 41.69292425696075,
                 y: -2.133550583025831,
             },
-            Coordinate {
+            Coord {
                 x: 42.185477159763316,
                 y: -1.8576483944773186,
             },
-            Coordinate {
+            Coord {
                 x: 42.75553809694794,
                 y: -1.568571339317774,
             },
-            Coordinate {
+            Coord {
                 x: 43.35626628803245,
                 y: -0.9338686737967923,
             },
-            Coordinate {
+            Coord {
                 x: 43.734110989528,
                 y: -0.4944010235847626,
             },
-            Coordinate {
+            Coord {
                 x: 44.24983444846293,
                 y: 0.500327599901363,
             },
-            Coordinate {
+            Coord {
                 x: 44.42846730644935,
                 y: 0.8720306662919768,
             },
-            Coordinate {
+            Coord {
                 x: 44.68988003338898,
                 y: 1.9413655225375614,
             },
-            Coordinate {
+            Coord {
                 x: 44.73777311953353,
                 y: 2.165594565597668,
             },
-            Coordinate {
+            Coord {
                 x: 44.79438872670807,
                 y: 3.3254998260869555,
             },
-            Coordinate {
+            Coord {
                 x: 44.79598586445366,
                 y: 3.43459397749277,
             },
-            Coordinate {
+            Coord {
                 x: 44.551636197387516,
                 y: 4.579184494920174,
             },
-            Coordinate {
+            Coord {
                 x: 44.54376824468085,
                 y: 4.608298744680852,
             },
-            Coordinate {
+            Coord {
                 x: 44.088449173553705,
                 y: 5.486198931129499,
             },
-            Coordinate {
+            Coord {
                 x: 43.598063178294574,
                 y: 6.4572989478505995,
             },
-            Coordinate {
+            Coord {
                 x: 42.52226148510668,
                 y: 7.3128364237025565,
             },
-            Coordinate {
+            Coord {
                 x: 42.50638091402014,
                 y: 7.325229593127244,
             },
-            Coordinate {
+            Coord {
                 x: 41.365619259259255,
                 y: 7.990255451178451,
             },
-            Coordinate {
+            Coord {
                 x: 40.21929826197005,
                 y: 8.30932831301413,
             },
-            Coordinate {
+            Coord {
                 x: 40.191910481614286,
                 y: 8.3105873387471,
             },
-            Coordinate {
+            Coord {
                 x: 38.373479641465316,
                 y: 8.380649535109473,
             },
-            Coordinate {
+            Coord {
                 x: 37.51849825174829,
                 y: 8.199783349650358,
             },
-            Coordinate {
+            Coord {
                 x: 37.29047026537997,
                 y: 8.153300885733085,
             },
-            Coordinate {
+            Coord {
                 x: 35.5739868973262,
                 y: 7.57506523828877,
             },
-            Coordinate {
+            Coord {
                 x: 35.518505652173914,
                 y: 7.556146305335969,
             },
-            Coordinate {
+            Coord {
                 x: 33.93036149195302,
                 y: 6.692874525445847,
             },
-            Coordinate {
+            Coord {
                 x: 31.944797801710486,
                 y: 5.236909567627495,
             },
-            Coordinate {
+            Coord {
                 x: 31.78258482352941,
                 y: 5.113927253796792,
             },
-            Coordinate {
+            Coord {
                 x: 30.18008338244514,
                 y: 3.308276926123303,
             },
-            Coordinate {
+            Coord {
                 x: 30.000945714285713,
                 y: 3.1050520995671,
             },
-            Coordinate {
+            Coord {
                 x: 28.37655575757576,
                 y: 1.1181568614718627,
             },
-            Coordinate {
+            Coord {
                 x: 28.197941004838874,
                 y: 0.9051253861834657,
             },
-            Coordinate {
+            Coord {
                 x: 26.238901999127968,
                 y: -0.9290026762589918,
             },
-            Coordinate {
+            Coord {
                 x: 26.156780508474576,
                 y: -1.005625550154083,
             },
-            Coordinate {
+            Coord {
                 x: 24.59227592592591,
                 y: -2.005733185185194,
             },
-            Coordinate {
+            Coord {
                 x: 23.879666279069767,
                 y: -2.447816217054264,
             },
-            Coordinate {
+            Coord {
                 x: 23.84657093023256,
                 y: -2.4702627286821706,
             },
-            Coordinate {
+            Coord {
                 x: 21.40088863298663,
                 y: -3.538301937592868,
             },
-            Coordinate {
+            Coord {
                 x: 21.260826671619615,
                 y: -3.6118359643387814,
             },
-            Coordinate {
+            Coord {
                 x: 19.013587550439116,
                 y: -4.486142778067885,
             },
-            Coordinate {
+            Coord {
                 x: 18.526589765013053,
                 y: -4.689803705435557,
             },
-            Coordinate {
+            Coord {
                 x: 15.75972842105263,
                 y: -5.470108403508772,
             },
-            Coordinate {
+            Coord {
                 x: 14.71079050367855,
                 y: -5.7870362440705865,
             },
-            Coordinate {
+            Coord {
                 x: 14.130166904357669,
                 y: -5.87134540891084,
             },
-            Coordinate {
+            Coord {
                 x: 11.396836252723311,
                 y: -6.79111423073876,
             },
-            Coordinate {
+            Coord {
                 x: 12.836953866760663,
                 y: -7.314210095267088,
             },
-            Coordinate {
+            Coord {
                 x: 13.430737899189285,
                 y: -7.665385187169545,
             },
-            Coordinate {
+            Coord {
                 x: 15.891967692307693,
                 y: -8.278695832167832,
             },
-            Coordinate {
+            Coord {
                 x: 17.614775384615385,
                 y: -8.69158813986014,
             },
-            Coordinate {
+            Coord {
                 x: 20.508739439252338,
                 y: -10.285825400169923,
             },
-            Coordinate {
+            Coord {
                 x: 21.034942242990653,
                 y: -10.562739719626167,
             },
-            Coordinate {
+            Coord {
                 x: 21.33170522514868,
                 y: -10.839488317757008,
             },
-            Coordinate {
+            Coord {
                 x: 22.943262058547067,
                 y: -12.314627400295421,
             },
-            Coordinate {
+            Coord {
                 x: 23.2693244911264,
                 y: -13.196186254980075,
             },
-            Coordinate {
+            Coord {
                 x: 23.87570205288796,
                 y: -15.025043110647182,
             },
-            Coordinate {
+            Coord {
                 x: 23.88745512820513,
                 y: -15.329726244343894,
             },
-            Coordinate {
+            Coord {
                 x: 23.960685734265734,
                 y: -17.477934475524474,
             },
-            Coordinate {
+            Coord {
                 x: 23.6133433965311,
                 y: -19.34018309210526,
             },
-            Coordinate {
+            Coord {
                 x: 23.49620768022841,
                 y: -19.898904389721626,
             },
-            Coordinate {
+            Coord {
                 x: 23.29821013039044,
                 y: -20.73196482371795,
             },
-            Coordinate {
+            Coord {
                 x: 22.744271335379892,
                 y: -22.771500805832694,
             },
-            Coordinate {
+            Coord {
                 x: 22.167913473053893,
                 y: -24.710688473053892,
             },
-            Coordinate {
+            Coord {
                 x: 21.899988487232672,
                 y: -25.71286526357199,
             },
-            Coordinate {
+            Coord {
                 x: 21.785349664147773,
                 y: -26.8158580604534,
             },
-            Coordinate {
+            Coord {
                 x: 21.62434375,
                 y: -28.56073125,
             },
-            Coordinate {
+            Coord {
                 x: 21.778491344537816,
                 y: -29.254584957983194,
             },
-            Coordinate {
+            Coord {
                 x: 21.9928726215291,
                 y: -30.22546573221757,
             },
-            Coordinate {
+            Coord {
                 x: 22.298107250859108,
                 y: -30.830159278350514,
             },
-            Coordinate {
+            Coord {
                 x: 22.703645194250193,
                 y: -31.61432564102564,
             },
-            Coordinate {
+            Coord {
                 x: 23.150684988776653,
                 y: -32.04120379188713,
             },
-            Coordinate {
+            Coord {
                 x: 23.666477301561873,
                 y: -32.53162973568282,
             },
-            Coordinate {
+            Coord {
                 x: 24.352727837076323,
                 y: -32.8549336827712,
             },
-            Coordinate {
+            Coord {
                 x: 25.12504182648402,
                 y: -33.18867739726027,
             },
-            Coordinate {
+            Coord {
                 x: 26.256443471758022,
                 y: -33.42552113970588,
             },
-            Coordinate {
+            Coord {
                 x: 27.18248221033868,
                 y: -33.62357801120448,
             },
-            Coordinate {
+            Coord {
                 x: 27.94577306949807,
                 y: -33.50700048262548,
             },
-            Coordinate {
+            Coord {
                 x: 28.809313944395413,
                 y: -33.351192718446605,
             },
-            Coordinate {
+            Coord {
                 x: 29.51190471226022,
                 y: -33.14606467889909,
             },
-            Coordinate {
+            Coord {
                 x: 30.20681809815951,
                 y: -32.91714938650307,
             },
-            Coordinate {
+            Coord {
                 x: 30.772219131701632,
                 y: -32.48469230769231,
             },
-            Coordinate {
+            Coord {
                 x: 31.269684328633932,
                 y: -32.07654005347594,
             },
-            Coordinate {
+            Coord {
                 x: 31.814980518018018,
                 y: -31.44050518018018,
             },
-            Coordinate {
+            Coord {
                 x: 32.21083703009828,
                 y: -30.958643693693695,
             },
-            Coordinate {
+            Coord {
                 x: 32.55321905118602,
                 y: -29.88120468164794,
             },
-            Coordinate {
+            Coord {
                 x: 32.73880441494149,
                 y: -29.18182376237624,
             },
-            Coordinate {
+            Coord {
                 x: 32.80154325208307,
                 y: -28.19137339261286,
             },
-            Coordinate {
+            Coord {
                 x: 32.81496887317013,
                 y: -27.68899303112314,
             },
-            Coordinate {
+            Coord {
                 x: 32.50266570848146,
                 y: -26.861685754189942,
             },
-            Coordinate {
+            Coord {
                 x: 32.39781927272727,
                 y: -26.510591276595743,
             },
-            Coordinate {
+            Coord {
                 x: 31.365036702127657,
                 y: -25.29672393617021,
             },
-            Coordinate {
+            Coord {
                 x: 31.18817417218543,
                 y: -25.083996854304633,
             },
-            Coordinate {
+            Coord {
                 x: 30.96186148325359,
                 y: -24.96392033492823,
             },
-            Coordinate {
+            Coord {
                 x: 29.730729591836745,
                 y: -24.11543265306123,
             },
-            Coordinate {
+            Coord {
                 x: 29.656726744186052,
                 y: -24.05927093023256,
             },
-            Coordinate {
+            Coord {
                 x: 29.60786635220126,
                 y: -24.02293364779874,
             },
-            Coordinate {
+            Coord {
                 x: 28.20101297055058,
                 y: -23.729857981220658,
             },
-            Coordinate {
+            Coord {
                 x: 27.573002405063292,
                 y: -23.641001265822783,
             },
-            Coordinate {
+            Coord {
                 x: 26.692397564593303,
                 y: -23.939743684210526,
             },
-            Coordinate {
+            Coord {
                 x: 25.820720253164556,
                 y: -24.25473101265823,
             },
-            Coordinate {
+            Coord {
                 x: 25.080241257689675,
                 y: -24.83151992481203,
             },
-            Coordinate {
+            Coord {
                 x: 24.77587464968153,
                 y: -25.06351433121019,
             },
-            Coordinate {
+            Coord {
                 x: 24.731795656401946,
                 y: -25.055063776337114,
             },
-            Coordinate {
+            Coord {
                 x: 24.7241,
                 y: -25.01283,
             },
-            Coordinate {
+            Coord {
                 x: 25.390740501228503,
                 y: -24.453634324324323,
             },
-            Coordinate {
+            Coord {
                 x: 25.755809375000002,
                 y: -24.138696875,
             },
-            Coordinate {
+            Coord {
                 x: 26.646257086614167,
                 y: -23.764686220472445,
             },
-            Coordinate {
+            Coord {
                 x: 27.50551998284734,
                 y: -23.41652547169811,
             },
-            Coordinate {
+            Coord {
                 x: 29.63244676963812,
                 y: -23.502961650485435,
             },
-            Coordinate {
+            Coord {
                 x: 29.774809493670883,
                 y: -23.512658860759494,
             },
-            Coordinate {
+            Coord {
                 x: 29.89847020202021,
                 y: -23.575248989898995,
             },
-            Coordinate {
+            Coord {
                 x: 31.589993333333332,
                 y: -24.5268525,
             },
-            Coordinate {
+            Coord {
                 x: 31.844447168271582,
                 y: -24.82213276481149,
             },
-            Coordinate {
+            Coord {
                 x: 33.13353828571428,
                 y: -26.132203714285712,
             },
-            Coordinate {
+            Coord {
                 x: 33.55147932974866,
                 y: -27.06839484181568,
             },
-            Coordinate {
+            Coord {
                 x: 33.80086793478261,
                 y: -27.687474048913042,
             },
-            Coordinate {
+            Coord {
                 x: 33.78803503067485,
                 y: -28.661053803680982,
             },
-            Coordinate {
+            Coord {
                 x: 33.76582250647741,
                 y: -29.40860724907063,
             },
-            Coordinate {
+            Coord {
                 x: 33.466707086846114,
                 y: -30.482701955307263,
             },
-            Coordinate {
+            Coord {
                 x: 33.17527069741282,
                 y: -31.51056507311586,
             },
-            Coordinate {
+            Coord {
                 x: 32.62682653504744,
                 y: -32.168572222222224,
             },
-            Coordinate {
+            Coord {
                 x: 32.04635688990115,
                 y: -32.880526652452026,
             },
-            Coordinate {
+            Coord {
                 x: 31.34967225342598,
                 y: -33.43459076433121,
             },
-            Coordinate {
+            Coord {
                 x: 30.726345612527716,
                 y: -33.93201798780488,
             },
-            Coordinate {
+            Coord {
                 x: 29.777996060606064,
                 y: -34.28924343434343,
             },
-            Coordinate {
+            Coord {
                 x: 28.998282084534104,
                 y: -34.55946325648415,
             },
-            Coordinate {
+            Coord {
                 x: 27.99265371443442,
                 y: -34.716202671755724,
             },
-            Coordinate {
+            Coord {
                 x: 27.1951319038817,
                 y: -34.845439648798525,
             },
-            Coordinate {
+            Coord {
                 x: 25.876675432088497,
                 y: -34.62063536139067,
             },
-            Coordinate {
+            Coord {
                 x: 24.867799862283793,
                 y: -34.43011844003607,
             },
-            Coordinate {
+            Coord {
                 x: 23.773830313901346,
                 y: -33.904452959641254,
             },
-            Coordinate {
+            Coord {
                 x: 22.976305681818182,
                 y: -33.530075,
             },
-            Coordinate {
+            Coord {
                 x: 22.444271147351376,
                 y: -33.047147455470736,
             },
-            Coordinate {
+            Coord {
                 x: 21.63122400508044,
                 y: -32.30165245554615,
             },
-            Coordinate {
+            Coord {
                 x: 21.281324027589324,
                 y: -31.631710447761193,
             },
-            Coordinate {
+            Coord {
                 x: 20.747026334164588,
                 y: -30.60282306733167,
             },
-            Coordinate {
+            Coord {
                 x: 20.559623673469385,
                 y: -29.841175714285715,
             },
-            Coordinate {
+            Coord {
                 x: 20.25604131147541,
                 y: -28.64845393442623,
             },
-            Coordinate {
+            Coord {
                 x: 20.333511631799162,
                 y: -26.710274351464435,
             },
-            Coordinate {
+            Coord {
                 x: 20.3675689693155,
                 y: -25.517690991345397,
             },
-            Coordinate {
+            Coord {
                 x: 20.734327929089442,
                 y: -23.524123730862208,
             },
-            Coordinate {
+            Coord {
                 x: 20.95530039410244,
                 y: -22.46300903151422,
             },
-            Coordinate {
+            Coord {
                 x: 20.953675346708465,
                 y: -21.604954551724138,
             },
-            Coordinate {
+            Coord {
                 x: 20.94049112578903,
                 y: -19.61750153901217,
             },
-            Coordinate {
+            Coord {
                 x: 20.905276528799444,
                 y: -18.09694435114504,
             },
-            Coordinate {
+            Coord {
                 x: 20.905728963117607,
                 y: -17.62720490605428,
             },
-            Coordinate {
+            Coord {
                 x: 20.47834354620586,
                 y: -16.216294402704733,
             },
-            Coordinate {
+            Coord {
                 x: 20.4089721751026,
                 y: -15.972046306429549,
             },
-            Coordinate {
+            Coord {
                 x: 19.577352033310202,
                 y: -15.131401908396946,
             },
-            Coordinate {
+            Coord {
                 x: 19.278795269485347,
                 y: -14.808011812778602,
             },
-            Coordinate {
+            Coord {
                 x: 19.222147721194112,
                 y: -14.776514338781576,
             },
-            Coordinate {
+            Coord {
                 x: 18.170260000000003,
                 y: -14.160816666666667,
             },
-            Coordinate {
+            Coord {
                 x: 17.890593333333335,
                 y: -14.09365,
             },
-            Coordinate {
+            Coord {
                 x: 16.24134816542015,
                 y: -13.747537725472943,
             },
-            Coordinate {
+            Coord {
                 x: 14.940157492300925,
                 y: -13.895180268367795,
             },
-            Coordinate {
+            Coord {
                 x: 12.854184330616997,
                 y: -14.07213533178114,
             },
-            Coordinate {
+            Coord {
                 x: 11.234649095847885,
                 y: -14.794774291812185,
             },
-            Coordinate {
+            Coord {
                 x: 9.542914913294798,
                 y: -15.460211849710984,
             },
-            Coordinate {
+            Coord {
                 x: 9.328692727272728,
                 y: -16.28455,
             },
-            Coordinate {
+            Coord {
                 x: 9.180059,
                 y: -16.65895,
             },
-            Coordinate {
+            Coord {
                 x: 9.838116487922704,
                 y: -19.835157729468598,
             },
-            Coordinate {
+            Coord {
                 x: 9.838535471366066,
                 y: -19.84048090909091,
             },
-            Coordinate {
+            Coord {
                 x: 9.843282090909092,
                 y: -19.884057692307714,
             },
-            Coordinate {
+            Coord {
                 x: 10.16075425981873,
                 y: -23.12924003021148,
             },
-            Coordinate {
+            Coord {
                 x: 10.125056978851964,
                 y: -23.26589501510574,
             },
-            Coordinate {
+            Coord {
                 x: 9.216824990328819,
                 y: -26.40247340425532,
             },
-            Coordinate {
+            Coord {
                 x: 9.136165382978724,
                 y: -26.491198936170214,
             },
-            Coordinate {
+            Coord {
                 x: 6.752372333333357,
                 y: -28.91480333333331,
             },
-            Coordinate {
+            Coord {
                 x: 6.659293893617023,
                 y: -29.007941063829787,
             },
-            Coordinate {
+            Coord {
                 x: 6.6529661459415586,
                 y: -29.014493392857144,
             },
-            Coordinate {
+            Coord {
                 x: 4.698182098569158,
                 y: -30.416987678855325,
             },
-            Coordinate {
+            Coord {
                 x: 4.458449083887657,
                 y: -30.560658536585365,
             },
-            Coordinate {
+            Coord {
                 x: 2.8004379935562524,
                 y: -31.483349042145594,
             },
-            Coordinate {
+            Coord {
                 x: 2.571658720671444,
                 y: -31.619197302904563,
             },
-            Coordinate {
+            Coord {
                 x: 0.045879814072229796,
                 y: -32.72151242661448,
             },
-            Coordinate {
+            Coord {
                 x: -0.49560151719367596,
                 y: -32.97893695652174,
             },
-            Coordinate {
+            Coord {
                 x: -1.9678976851248429,
                 y: -33.70592518557795,
             },
-            Coordinate {
+            Coord {
                 x: -2.429041752688172,
                 y: -33.97243752688172,
             },
-            Coordinate {
+            Coord {
                 x: -3.6893136415694587,
                 y: -35.124571420996816,
             },
-            Coordinate {
+            Coord {
                 x: -4.110945325031134,
                 y: -35.50573082191781,
             },
-            Coordinate {
+            Coord {
                 x: -4.854566009861325,
                 y: -36.52081101694915,
             },
-            Coordinate {
+            Coord {
                 x: -5.093412482276898,
                 y: -36.9308627293578,
             },
-            Coordinate {
+            Coord {
                 x: -5.193135560076288,
                 y: -37.35553531468531,
             },
-            Coordinate {
+            Coord {
                 x: -5.439305760997067,
                 y: -38.368831451612905,
             },
-            Coordinate {
+            Coord {
                 x: -5.392446314131414,
                 y: -39.9667301980198,
             },
-            Coordinate {
+            Coord {
                 x: -5.358187656260306,
                 y: -40.839122914147524,
             },
-            Coordinate {
+            Coord {
                 x: -4.679698569417581,
                 y: -42.22456702127659,
             },
-            Coordinate {
+            Coord {
                 x: -4.243529647869674,
                 y: -43.07155764411027,
             },
-            Coordinate {
+            Coord {
                 x: -3.1862447623642947,
                 y: -43.82638148371532,
             },
-            Coordinate {
+            Coord {
                 x: -2.4289130139122093,
                 y: -44.394928100263854,
             },
-            Coordinate {
+            Coord {
                 x: -1.2471657190606964,
                 y: -44.64322220543807,
             },
-            Coordinate {
+            Coord {
                 x: -0.20417073018284831,
                 y: -44.8486902266289,
             },
-            Coordinate {
+            Coord {
                 x: 1.208624985002309,
                 y: -44.64445592216582,
             },
-            Coordinate {
+            Coord {
                 x: 1.903140907668232,
                 y: -44.52301025039124,
             },
-            Coordinate {
+            Coord {
                 x: 3.056799810093822,
                 y: -43.88302046263345,
             },
-            Coordinate {
+            Coord {
                 x: 3.3824261181054243,
                 y: -43.6993631092437,
             },
-            Coordinate {
+            Coord {
                 x: 4.3699307269155225,
                 y: -42.633768860510806,
             },
-            Coordinate {
+            Coord {
                 x: 4.461576455696203,
                 y: -42.52936265822785,
             },
-            Coordinate {
+            Coord {
                 x: 4.89566191204589,
                 y: -41.32762131931166,
             },
-            Coordinate {
+            Coord {
                 x: 4.9119162962962974,
                 y: -41.28979888888889,
             },
-            Coordinate {
+            Coord {
                 x: 4.922251366906475,
                 y: -41.034779496402884,
             },
-            Coordinate {
+            Coord {
                 x: 5.033826523605151,
                 y: -40.4017517167382,
             },
-            Coordinate {
+            Coord {
                 x: 5.207429190909091,
                 y: -39.690000000000005,
             },
-            Coordinate {
+            Coord {
                 x: 5.55887566666667,
                 y: -40.828550000000014,
             },
-            Coordinate {
+            Coord {
                 x: 5.678565039348712,
                 y: -41.205163432835825,
             },
-            Coordinate {
+            Coord {
                 x: 5.688562474903475,
                 y: -41.35776196911197,
             },
-            Coordinate {
+            Coord {
                 x: 5.330239621572211,
                 y: -42.800707038391224,
             },
-            Coordinate {
+            Coord {
                 x: 5.291449197939172,
                 y: -42.92854634369287,
             },
-            Coordinate {
+            Coord {
                 x: 4.003079095121095,
                 y: -44.28245579150579,
             },
-            Coordinate {
+            Coord {
                 x: 3.983055202531645,
                 y: -44.309933544303796,
             },
-            Coordinate {
+            Coord {
                 x: 3.9488388358995996,
                 y: -44.335744895591645,
             },
-            Coordinate {
+            Coord {
                 x: 2.4847500390624995,
                 y: -45.4532466796875,
             },
-            Coordinate {
+            Coord {
                 x: 1.9082936874325696,
                 y: -45.60591659340659,
             },
-            Coordinate {
+            Coord {
                 x: -0.1710241228142293,
                 y: -46.13201956521739,
             },
-            Coordinate {
+            Coord {
                 x: -1.2513467282225224,
                 y: -45.96203432835821,
             },
-            Coordinate {
+            Coord {
                 x: -2.8306697750071246,
                 y: -45.7819539184953,
             },
-            Coordinate {
+            Coord {
                 x: -3.950447106035888,
                 y: -45.01115391517129,
             },
-            Coordinate {
+            Coord {
                 x: -4.96529236522661,
                 y: -44.33111093294461,
             },
-            Coordinate {
+            Coord {
                 x: -6.138661928620546,
                 y: -42.76974954614221,
             },
-            Coordinate {
+            Coord {
                 x: -6.908923115074798,
                 y: -41.711520886075945,
             },
-            Coordinate {
+            Coord {
                 x: -7.259640909090908,
                 y: -39.401450000000004,
             },
-            Coordinate {
+            Coord {
                 x: -7.451395713666466,
                 y: -38.22152523178808,
             },
-            Coordinate {
+            Coord {
                 x: -7.366029692307692,
                 y: -37.57332692307692,
             },
-            Coordinate {
+            Coord {
                 x: -7.1679019554437655,
                 y: -35.93239308300395,
             },
-            Coordinate {
+            Coord {
                 x: -6.869847152211772,
                 y: -35.35990095785441,
             },
-            Coordinate {
+            Coord {
                 x: -6.127569640176337,
                 y: -33.698801900393185,
             },
-            Coordinate {
+            Coord {
                 x: -5.594131811461387,
                 y: -33.10744596774193,
             },
-            Coordinate {
+            Coord {
                 x: -4.130437571779572,
                 y: -31.59611344916345,
             },
-            Coordinate {
+            Coord {
                 x: -3.5173405850843755,
                 y: -31.17561491017964,
             },
-            Coordinate {
+            Coord {
                 x: -1.7785765537820957,
                 y: -30.151353435114505,
             },
-            Coordinate {
+            Coord {
                 x: -1.1048699961685808,
                 y: -29.784088314176245,
             },
-            Coordinate {
+            Coord {
                 x: 0.7570252456965089,
                 y: -28.7491283269962,
             },
-            Coordinate {
+            Coord {
                 x: 1.7070045284552842,
                 y: -28.072885772357726,
             },
-            Coordinate {
+            Coord {
                 x: 1.946997506913446,
                 y: -27.883432035928145,
             },
-            Coordinate {
+            Coord {
                 x: 3.16385630744868,
                 y: -26.735243161290324,
             },
-            Coordinate {
+            Coord {
                 x: 3.3197360270270275,
                 y: -26.577503378378378,
             },
-            Coordinate {
+            Coord {
                 x: 4.069082329283111,
                 y: -24.967709538274605,
             },
-            Coordinate {
+            Coord {
                 x: 4.096999657912458,
                 y: -24.918623015873013,
             },
-            Coordinate {
+            Coord {
                 x: 4.241111983086683,
                 y: -23.781145348837192,
             },
-            Coordinate {
+            Coord {
                 x: 4.391154632569903,
                 y: -22.70646916537867,
             },
-            Coordinate {
+            Coord {
                 x: 4.393993719685261,
                 y: -22.671712751159195,
             },
-            Coordinate {
+            Coord {
                 x: 2.7795412443181813,
                 y: -20.17895,
             },
-            Coordinate {
+            Coord {
                 x: 2.77126,
                 y: -20.165075,
             },
-            Coordinate {
+            Coord {
                 x: 2.7542037534090915,
                 y: -20.153912500000004,
             },
-            Coordinate {
+            Coord {
                 x: 0.025835602247999356,
                 y: -17.955661078140455,
             },
-            Coordinate {
+            Coord {
                 x: -0.8259763841472227,
                 y: -17.43118473585788,
             },
-            Coordinate {
+            Coord {
                 x: -1.2812667311827954,
                 y: -17.171464913510988,
             },
-            Coordinate {
+            Coord {
                 x: -1.7098254975137062,
                 y: -16.923327793361384,
             },
-            Coordinate {
+            Coord {
                 x: -2.395120673306773,
                 y: -16.536210779105797,
             },
-            Coordinate {
+            Coord {
                 x: -3.2639064452493054,
                 y: -16.02521759628154,
             },
-            Coordinate {
+            Coord {
                 x: -4.389581278085515,
                 y: -15.442883293508563,
             },
-            Coordinate {
+            Coord {
                 x: -5.31830235207567,
                 y: -15.060421098265897,
             },
-            Coordinate {
+            Coord {
                 x: -6.605387522858644,
                 y: -14.437084682080926,
             },
-            Coordinate {
+            Coord {
                 x: -7.681890009171838,
                 y: -14.286312611275964,
             },
-            Coordinate {
+            Coord {
                 x: -8.847372463717292,
                 y: -14.112098961424332,
             },
-            Coordinate {
+            Coord {
                 x: -10.738085244752531,
                 y: -14.072642355452077,
             },
-            Coordinate {
+            Coord {
                 x: -12.613717997671712,
                 y: -14.007504637175009,
             },
-            Coordinate {
+            Coord {
                 x: -15.055914748544204,
                 y: -13.978453493912124,
             },
-            Coordinate {
+            Coord {
                 x: -16.04459367919534,
                 y: -13.971993885653784,
             },
-            Coordinate {
+            Coord {
                 x: -17.804206666666666,
                 y: -14.369250000000001,
             },
-            Coordinate {
+            Coord {
                 x: -18.10954,
                 y: -14.436416666666666,
             },
-            Coordinate {
+            Coord {
                 x: -19.24129447791436,
                 y: -15.033712555720653,
             },
-            Coordinate {
+            Coord {
                 x: -19.30189418073754,
                 y: -15.06425193164933,
             },
-            Coordinate {
+            Coord {
                 x: -19.600141603053434,
                 y: -15.387701908396945,
             },
-            Coordinate {
+            Coord {
                 x: -20.431102881482403,
                 y: -16.22834630642955,
             },
-            Coordinate {
+            Coord {
                 x: -20.50017615531726,
                 y: -16.468695078888054,
             },
-            Coordinate {
+            Coord {
                 x: -20.92749130322009,
                 y: -17.85862807933194,
             },
-            Coordinate {
+            Coord {
                 x: -20.927514009715473,
                 y: -18.31097824427481,
             },
-            Coordinate {
+            Coord {
                 x: -20.964373285612027,
                 y: -19.771699821045097,
             },
-            Coordinate {
+            Coord {
                 x: -20.976758344827587,
                 y: -21.67049137931034,
             },
-            Coordinate {
+            Coord {
                 x: -20.976913481936972,
                 y: -22.490506341275943,
             },
-            Coordinate {
+            Coord {
                 x: -20.749315906526995,
                 y: -23.54270503626108,
             },
-            Coordinate {
+            Coord {
                 x: -20.367555312924683,
                 y: -25.517690991345397,
             },
-            Coordinate {
+            Coord {
                 x: -20.333497742868012,
                 y: -26.710274351464435,
             },
-            Coordinate {
+            Coord {
                 x: -20.256021311475408,
                 y: -28.64845393442623,
             },
-            Coordinate {
+            Coord {
                 x: -20.559603673469386,
                 y: -29.841175714285715,
             },
-            Coordinate {
+            Coord {
                 x: -20.74700633416459,
                 y: -30.60282306733167,
             },
-            Coordinate {
+            Coord {
                 x: -21.28130990049751,
                 y: -31.631710447761193,
             },
-            Coordinate {
+            Coord {
                 x: -21.63121400508044,
                 y: -32.30165245554615,
             },
-            Coordinate {
+            Coord {
                 x: -22.444255351993213,
                 y: -33.047147455470736,
             },
-            Coordinate {
+            Coord {
                 x: -22.97629,
                 y: -33.530075,
             },
-            Coordinate {
+            Coord {
                 x: -23.773810313901347,
                 y: -33.904452959641254,
             },
-            Coordinate {
+            Coord {
                 x: -24.867785446348062,
                 y: -34.43011844003607,
             },
-            Coordinate {
+            Coord {
                 x: -25.876665432088497,
                 y: -34.62063536139067,
             },
-            Coordinate {
+            Coord {
                 x: -27.195116328348178,
                 y: -34.845439648798525,
             },
-            Coordinate {
+            Coord {
                 x: -27.99263828244275,
                 y: -34.716202671755724,
             },
-            Coordinate {
+            Coord {
                 x: -28.998272084534104,
                 y: -34.55946325648415,
             },
-            Coordinate {
+            Coord {
                 x: -29.77798606060606,
                 y: -34.28924343434343,
             },
-            Coordinate {
+            Coord {
                 x: -30.726330345528456,
                 y: -33.93201798780488,
             },
-            Coordinate {
+            Coord {
                 x: -31.34965719745223,
                 y: -33.43459076433121,
             },
-            Coordinate {
+            Coord {
                 x: -32.04634181236674,
                 y: -32.880526652452026,
             },
-            Coordinate {
+            Coord {
                 x: -32.62681171717172,
                 y: -32.168572222222224,
             },
-            Coordinate {
+            Coord {
                 x: -33.17526552612741,
                 y: -31.51056507311586,
             },
-            Coordinate {
+            Coord {
                 x: -33.46669229050279,
                 y: -30.482701955307263,
             },
-            Coordinate {
+            Coord {
                 x: -33.76580827757125,
                 y: -29.40860724907063,
             },
-            Coordinate {
+            Coord {
                 x: -33.78802921807027,
                 y: -28.661053803680982,
             },
-            Coordinate {
+            Coord {
                 x: -33.80086793478261,
                 y: -27.687474048913042,
             },
-            Coordinate {
+            Coord {
                 x: -33.55147211454295,
                 y: -27.06839484181568,
             },
-            Coordinate {
+            Coord {
                 x: -33.13351828571428,
                 y: -26.132203714285712,
             },
-            Coordinate {
+            Coord {
                 x: -31.84444385506773,
                 y: -24.82213276481149,
             },
-            Coordinate {
+            Coord {
                 x: -31.589991795454544,
                 y: -24.5268525,
             },
-            Coordinate {
+            Coord {
                 x: -29.898460881542707,
                 y: -23.575248989898995,
             },
-            Coordinate {
+            Coord {
                 x: -29.77479991944764,
                 y: -23.512658860759494,
             },
-            Coordinate {
+            Coord {
                 x: -29.63243676963812,
                 y: -23.502961650485435,
             },
-            Coordinate {
+            Coord {
                 x: -27.50550998284734,
                 y: -23.41652547169811,
             },
-            Coordinate {
+            Coord {
                 x: -26.646247086614167,
                 y: -23.764686220472445,
             },
-            Coordinate {
+            Coord {
                 x: -25.755799375000002,
                 y: -24.138696875,
             },
-            Coordinate {
+            Coord {
                 x: -25.390730501228504,
                 y: -24.453634324324323,
             },
-            Coordinate {
+            Coord {
                 x: -24.724082454545456,
                 y: -25.01283,
             },
-            Coordinate {
+            Coord {
                 x: -24.731775656401947,
                 y: -25.055063776337114,
             },
-            Coordinate {
+            Coord {
                 x: -24.77585464968153,
                 y: -25.06351433121019,
             },
-            Coordinate {
+            Coord {
                 x: -25.08022716336295,
                 y: -24.83151992481203,
             },
-            Coordinate {
+            Coord {
                 x: -25.820714321058688,
                 y: -24.25473101265823,
             },
-            Coordinate {
+            Coord {
                 x: -26.69239263157895,
                 y: -23.939743684210526,
             },
-            Coordinate {
+            Coord {
                 x: -27.572986311852706,
                 y: -23.641001265822783,
             },
-            Coordinate {
+            Coord {
                 x: -28.20099876653863,
                 y: -23.729857981220658,
             },
-            Coordinate {
+            Coord {
                 x: -29.607856838193253,
                 y: -24.02293364779874,
             },
-            Coordinate {
+            Coord {
                 x: -29.65671674418605,
                 y: -24.05927093023256,
             },
-            Coordinate {
+            Coord {
                 x: -29.730719591836742,
                 y: -24.11543265306123,
             },
-            Coordinate {
+            Coord {
                 x: -30.96185148325359,
                 y: -24.96392033492823,
             },
-            Coordinate {
+            Coord {
                 x: -31.18816566676701,
                 y: -25.083996854304633,
             },
-            Coordinate {
+            Coord {
                 x: -31.36502830270793,
                 y: -25.29672393617021,
             },
-            Coordinate {
+            Coord {
                 x: -32.3978058336557,
                 y: -26.510591276595743,
             },
-            Coordinate {
+            Coord {
                 x: -32.50265216861351,
                 y: -26.861685754189942,
             },
-            Coordinate {
+            Coord {
                 x: -32.81495575470538,
                 y: -27.68899303112314,
             },
-            Coordinate {
+            Coord {
                 x: -32.801526730506154,
                 y: -28.19137339261286,
             },
-            Coordinate {
+            Coord {
                 x: -32.738788514851485,
                 y: -29.18182376237624,
             },
-            Coordinate {
+            Coord {
                 x: -32.55319905118602,
                 y: -29.88120468164794,
             },
-            Coordinate {
+            Coord {
                 x: -32.21082231981982,
                 y: -30.958643693693695,
             },
-            Coordinate {
+            Coord {
                 x: -31.81497051801802,
                 y: -31.44050518018018,
             },
-            Coordinate {
+            Coord {
                 x: -31.269669304812833,
                 y: -32.07654005347594,
             },
-            Coordinate {
+            Coord {
                 x: -30.772204102564103,
                 y: -32.48469230769231,
             },
-            Coordinate {
+            Coord {
                 x: -30.206812911321805,
                 y: -32.91714938650307,
             },
-            Coordinate {
+            Coord {
                 x: -29.511894370308593,
                 y: -33.14606467889909,
             },
-            Coordinate {
+            Coord {
                 x: -28.809298543689323,
                 y: -33.351192718446605,
             },
-            Coordinate {
+            Coord {
                 x: -27.94576306949807,
                 y: -33.50700048262548,
             },
-            Coordinate {
+            Coord {
                 x: -27.182467731092437,
                 y: -33.62357801120448,
             },
-            Coordinate {
+            Coord {
                 x: -26.256433471758022,
                 y: -33.42552113970588,
             },
-            Coordinate {
+            Coord {
                 x: -25.125026271481943,
                 y: -33.18867739726027,
             },
-            Coordinate {
+            Coord {
                 x: -24.352723381950774,
                 y: -32.8549336827712,
             },
-            Coordinate {
+            Coord {
                 x: -23.666468689627553,
                 y: -32.53162973568282,
             },
-            Coordinate {
+            Coord {
                 x: -23.15067638688472,
                 y: -32.04120379188713,
             },
-            Coordinate {
+            Coord {
                 x: -22.703636857031857,
                 y: -31.61432564102564,
             },
-            Coordinate {
+            Coord {
                 x: -22.29808725085911,
                 y: -30.830159278350514,
             },
-            Coordinate {
+            Coord {
                 x: -21.992856652719666,
                 y: -30.22546573221757,
             },
-            Coordinate {
+            Coord {
                 x: -21.778481344537816,
                 y: -29.254584957983194,
             },
-            Coordinate {
+            Coord {
                 x: -21.62433375,
                 y: -28.56073125,
             },
-            Coordinate {
+            Coord {
                 x: -21.785339664147774,
                 y: -26.8158580604534,
             },
-            Coordinate {
+            Coord {
                 x: -21.899974933123524,
                 y: -25.71286526357199,
             },
-            Coordinate {
+            Coord {
                 x: -22.184268443113773,
                 y: -24.71292005988024,
             },
-            Coordinate {
+            Coord {
                 x: -22.752349900230236,
                 y: -22.79079144282425,
             },
-            Coordinate {
+            Coord {
                 x: -23.282681666666665,
                 y: -20.824299599358977,
             },
-            Coordinate {
+            Coord {
                 x: -23.487229578872235,
                 y: -20.01993600999286,
             },
-            Coordinate {
+            Coord {
                 x: -23.89409766590389,
                 y: -18.150529252479025,
             },
-            Coordinate {
+            Coord {
                 x: -23.982254615384615,
                 y: -17.684429510489508,
             },
-            Coordinate {
+            Coord {
                 x: -23.90917224941725,
                 y: -15.580675867269987,
             },
-            Coordinate {
+            Coord {
                 x: -23.897830795217306,
                 y: -15.281213187195547,
             },
-            Coordinate {
+            Coord {
                 x: -23.264533277797895,
                 y: -13.47033804780876,
             },
-            Coordinate {
+            Coord {
                 x: -22.925378552437223,
                 y: -12.597459453471197,
             },
-            Coordinate {
+            Coord {
                 x: -21.04146242990654,
                 y: -11.18046308411215,
             },
-            Coordinate {
+            Coord {
                 x: -20.687960195412064,
                 y: -10.91777523364486,
             },
-            Coordinate {
+            Coord {
                 x: -20.145544672897195,
                 y: -10.618529138487682,
             },
-            Coordinate {
+            Coord {
                 x: -17.103963076923076,
                 y: -8.933795272727272,
             },
-            Coordinate {
+            Coord {
                 x: -15.58712132867133,
                 y: -8.42688758041958,
             },
-            Coordinate {
+            Coord {
                 x: -13.4088886579934,
                 y: -7.674483001762425,
             },
-            Coordinate {
+            Coord {
                 x: -13.050429249206909,
                 y: -7.448857199859006,
             },
-            Coordinate {
+            Coord {
                 x: -12.091443787878788,
                 y: -7.107898378787879,
             },
-            Coordinate {
+            Coord {
                 x: -11.850916666666667,
                 y: -6.956199,
             },
-            Coordinate {
+            Coord {
                 x: -11.396822440087146,
                 y: -6.79111423073876,
             },
-            Coordinate {
+            Coord {
                 x: -14.130149701600041,
                 y: -5.87134540891084,
             },
-            Coordinate {
+            Coord {
                 x: -14.710770503678551,
                 y: -5.7870362440705865,
             },
-            Coordinate {
+            Coord {
                 x: -15.759711218295005,
                 y: -5.470108403508772,
             },
-            Coordinate {
+            Coord {
                 x: -18.526571457393782,
                 y: -4.689803705435557,
             },
-            Coordinate {
+            Coord {
                 x: -19.01356924281984,
                 y: -4.486142778067885,
             },
-            Coordinate {
+            Coord {
                 x: -21.26081667161961,
                 y: -3.6118359643387814,
             },
-            Coordinate {
+            Coord {
                 x: -21.400879177360526,
                 y: -3.538301937592868,
             },
-            Coordinate {
+            Coord {
                 x: -23.84656106412967,
                 y: -2.4702627286821706,
             },
-            Coordinate {
+            Coord {
                 x: -23.879656279069767,
                 y: -2.447816217054264,
             },
-            Coordinate {
+            Coord {
                 x: -24.59226592592591,
                 y: -2.005733185185194,
             },
-            Coordinate {
+            Coord {
                 x: -26.156770508474576,
                 y: -1.005625550154083,
             },
-            Coordinate {
+            Coord {
                 x: -26.238891558752997,
                 y: -0.9290026762589918,
             },
-            Coordinate {
+            Coord {
                 x: -28.197939063111296,
                 y: 0.9051253861834657,
             },
-            Coordinate {
+            Coord {
                 x: -28.376553766233766,
                 y: 1.1181568614718627,
             },
-            Coordinate {
+            Coord {
                 x: -30.000925714285714,
                 y: 3.1050520995671,
             },
-            Coordinate {
+            Coord {
                 x: -30.180065353187043,
                 y: 3.308276926123303,
             },
-            Coordinate {
+            Coord {
                 x: -31.782575596791443,
                 y: 5.113927253796792,
             },
-            Coordinate {
+            Coord {
                 x: -31.944787801710486,
                 y: 5.236909567627495,
             },
-            Coordinate {
+            Coord {
                 x: -33.930351491953026,
                 y: 6.692874525445847,
             },
-            Coordinate {
+            Coord {
                 x: -35.518485938735175,
                 y: 7.556146305335969,
             },
-            Coordinate {
+            Coord {
                 x: -35.57396745561497,
                 y: 7.57506523828877,
             },
-            Coordinate {
+            Coord {
                 x: -37.29046029827832,
                 y: 8.153300885733085,
             },
-            Coordinate {
+            Coord {
                 x: -37.518488251748295,
                 y: 8.199783349650358,
             },
-            Coordinate {
+            Coord {
                 x: -38.37346956139729,
                 y: 8.380649535109473,
             },
-            Coordinate {
+            Coord {
                 x: -40.19190032482599,
                 y: 8.3105873387471,
             },
-            Coordinate {
+            Coord {
                 x: -40.21928826197005,
                 y: 8.30932831301413,
             },
-            Coordinate {
+            Coord {
                 x: -41.365599259259255,
                 y: 7.990255451178451,
             },
-            Coordinate {
+            Coord {
                 x: -42.50636091402014,
                 y: 7.325229593127244,
             },
-            Coordinate {
+            Coord {
                 x: -42.52224176818534,
                 y: 7.3128364237025565,
             },
-            Coordinate {
+            Coord {
                 x: -43.598063178294574,
                 y: 6.4572989478505995,
             },
-            Coordinate {
+            Coord {
                 x: -44.08843870523415,
                 y: 5.486198931129499,
             },
-            Coordinate {
+            Coord {
                 x: -44.543748453820115,
                 y: 4.608298744680852,
             },
-            Coordinate {
+            Coord {
                 x: -44.55161642564982,
                 y: 4.579184494920174,
             },
-            Coordinate {
+            Coord {
                 x: -44.795975864453666,
                 y: 3.43459397749277,
             },
-            Coordinate {
+            Coord {
                 x: -44.794378726708075,
                 y: 3.3254998260869555,
             },
-            Coordinate {
+            Coord {
                 x: -44.73775445666578,
                 y: 2.165594565597668,
             },
-            Coordinate {
+            Coord {
                 x: -44.68986003338898,
                 y: 1.9413655225375614,
             },
-            Coordinate {
+            Coord {
                 x: -44.42846298721372,
                 y: 0.8720306662919768,
             },
-            Coordinate {
+            Coord {
                 x: -44.24983444846293,
                 y: 0.500327599901363,
             },
-            Coordinate {
+            Coord {
                 x: -43.7340971907725,
                 y: -0.4944010235847626,
             },
-            Coordinate {
+            Coord {
                 x: -43.35625005531993,
                 y: -0.9338686737967923,
             },
-            Coordinate {
+            Coord {
                 x: -42.755528096947934,
                 y: -1.568571339317774,
             },
-            Coordinate {
+            Coord {
                 x: -42.18546223417608,
                 y: -1.8576483944773186,
             },
-            Coordinate {
+            Coord {
                 x: -41.69291375041932,
                 y: -2.133550583025831,
             },
-            Coordinate {
+            Coord {
                 x: -40.27500561497326,
                 y: -2.3360116363636374,
             },
-            Coordinate {
+            Coord {
                 x: -39.8694812133734,
                 y: -2.395053371900827,
             },
-            Coordinate {
+            Coord {
                 x: -39.589456367041194,
                 y: -2.3286056779026225,
             },
-            Coordinate {
+            Coord {
                 x: -38.47634833333334,
                 y: -2.1883396666666677,
             },
-            Coordinate {
+            Coord {
                 x: -38.2161611969112,
                 y: -2.1659966486486493,
             },
-            Coordinate {
+            Coord {
                 x: -37.974087681159425,
                 y: -2.1419871304347833,
             },
-            Coordinate {
+            Coord {
                 x: -37.0539276119403,
                 y: -1.5795664613297182,
             },
-            Coordinate {
+            Coord {
                 x: -36.22006639657444,
                 y: -0.9858103509222663,
             },
-            Coordinate {
+            Coord {
                 x: -35.99177703703704,
                 y: -0.5391715387766589,
             },
-            Coordinate {
+            Coord {
                 x: -35.39504343361674,
                 y: 0.6872540276651405,
             },
-            Coordinate {
+            Coord {
                 x: -35.36869496644295,
                 y: 0.8430525854179354,
             },
-            Coordinate {
+            Coord {
                 x: -35.202396043165464,
                 y: 1.9796570359712236,
             },
-            Coordinate {
+            Coord {
                 x: -35.19496343283582,
                 y: 1.9992811044776118,
             },
-            Coordinate {
+            Coord {
                 x: -35.18899888888889,
                 y: 2.0030520208754203,
             },
-            Coordinate {
+            Coord {
                 x: -35.18332720588235,
                 y: 1.9995644999999997,
             },
-            Coordinate {
+            Coord {
                 x: -35.17927251556662,
                 y: 1.98016295890411,
             },
-            Coordinate {
+            Coord {
                 x: -35.32909863636363,
                 y: 0.8354519136363611,
             },
-            Coordinate {
+            Coord {
                 x: -35.34827244897959,
                 y: 0.6730294510204083,
             },
-            Coordinate {
+            Coord {
                 x: -35.477730799999996,
                 y: 0.37502871701818696,
             },
-            Coordinate {
+            Coord {
                 x: -36.115894342751844,
                 y: -1.0443628648648646,
             },
-            Coordinate {
+            Coord {
                 x: -37.00456193722943,
                 y: -1.8467063333333287,
             },
-            Coordinate {
+            Coord {
                 x: -37.789531632653066,
                 y: -2.521901061224489,
             },
-            Coordinate {
+            Coord {
                 x: -38.414190163934435,
                 y: -2.7361684918032796,
             },
-            Coordinate {
+            Coord {
                 x: -39.766893383947945,
                 y: -3.1409050498915394,
             },
-            Coordinate {
+            Coord {
                 x: -40.31367253521127,
                 y: -3.1216282816901404,
             },
-            Coordinate {
+            Coord {
                 x: -42.02143525519848,
                 y: -3.146603387523629,
             },
-            Coordinate {
+            Coord {
                 x: -42.713810271646864,
                 y: -2.8895553005093375,
             },
-            Coordinate {
+            Coord {
                 x: -43.63621060070671,
                 y: -2.4977271519434625,
             },
-            Coordinate {
+            Coord {
                 x: -44.45220292929293,
                 y: -1.7099684040404046,
             },
-            Coordinate {
+            Coord {
                 x: -44.89526307888805,
                 y: -1.290085685950413,
             },
-            Coordinate {
+            Coord {
                 x: -45.6396889010989,
                 y: -0.04822107692307773,
             },
-            Coordinate {
+            Coord {
                 x: -45.84121546160361,
                 y: 0.2830372484472051,
             },
-            Coordinate {
+            Coord {
                 x: -46.26792260504202,
                 y: 1.8490084705882346,
             },
-            Coordinate {
+            Coord {
                 x: -46.32473221574344,
                 y: 2.046916139941691,
             },
-            Coordinate {
+            Coord {
                 x: -46.404162597200624,
                 y: 3.774479371695178,
             },
-            Coordinate {
+            Coord {
                 x: -46.409436394941146,
                 y: 3.8459839559228652,
             },
-            Coordinate {
+            Coord {
                 x: -46.095584935064934,
                 y: 5.201223428571447,
             },
-            Coordinate {
+            Coord {
                 x: -46.00843380681818,
                 y: 5.556670755681818,
             },
-            Coordinate {
+            Coord {
                 x: -44.78135558935361,
                 y: 7.759170283097131,
             },
-            Coordinate {
+            Coord {
                 x: -44.738533155893535,
                 y: 7.794885079847909,
             },
-            Coordinate {
+            Coord {
                 x: -43.457822011605415,
                 y: 8.838263605415861,
             },
-            Coordinate {
+            Coord {
                 x: -43.40168584139265,
                 y: 8.867055127659574,
             },
-            Coordinate {
+            Coord {
                 x: -41.77931794676806,
                 y: 9.681891956100932,
             },
-            Coordinate {
+            Coord {
                 x: -40.28151394636015,
                 y: 10.027840495646117,
             },
-            Coordinate {
+            Coord {
                 x: -40.24266796934866,
                 y: 10.03719827586207,
             },
-            Coordinate {
+            Coord {
                 x: -37.559808852459014,
                 y: 10.084569672131149,
             },
-            Coordinate {
+            Coord {
                 x: -37.54192852459016,
                 y: 10.085045628415301,
             },
-            Coordinate {
+            Coord {
                 x: -36.939243939393954,
                 y: 9.937350787878794,
             },
-            Coordinate {
+            Coord {
                 x: -36.01734770015187,
                 y: 9.704519049251465,
             },
-            Coordinate {
+            Coord {
                 x: -35.98279894671623,
                 y: 9.69223429582066,
             },
-            Coordinate {
+            Coord {
                 x: -33.53795731267545,
                 y: 8.877894686460808,
             },
-            Coordinate {
+            Coord {
                 x: -33.441694134225244,
                 y: 8.82275788814563,
             },
-            Coordinate {
+            Coord {
                 x: -31.772139266516756,
                 y: 7.8958668189313626,
             },
-            Coordinate {
+            Coord {
                 x: -31.6052274251497,
                 y: 7.760636314643439,
             },
-            Coordinate {
+            Coord {
                 x: -29.70315395348837,
                 y: 6.217383340380549,
             },
-            Coordinate {
+            Coord {
                 x: -29.50285204625763,
                 y: 6.00793946321876,
             },
-            Coordinate {
+            Coord {
                 x: -27.945216017615603,
                 y: 4.351800262661214,
             },
-            Coordinate {
+            Coord {
                 x: -27.724419729729732,
                 y: 4.114721394963144,
             },
-            Coordinate {
+            Coord {
                 x: -26.290111654749744,
                 y: 2.5278789662921346,
             },
-            Coordinate {
+            Coord {
                 x: -24.93602077798245,
                 y: 1.341469282479142,
             },
-            Coordinate {
+            Coord {
                 x: -24.810368790728603,
                 y: 1.2419652641873276,
             },
-            Coordinate {
+            Coord {
                 x: -23.20566711111111,
                 y: 0.5039109645656565,
             },
-            Coordinate {
+            Coord {
                 x: -23.19585550387597,
                 y: 0.49963446779422105,
             },
-            Coordinate {
+            Coord {
                 x: -23.04073999999999,
                 y: 0.4511722563636331,
             },
-            Coordinate {
+            Coord {
                 x: -21.075124246061517,
                 y: -0.19698205843960997,
             },
-            Coordinate {
+            Coord {
                 x: -20.956400990247563,
                 y: -0.21543464658664663,
             },
-            Coordinate {
+            Coord {
                 x: -18.43329769980507,
                 y: -0.5793399651899109,
             },
-            Coordinate {
+            Coord {
                 x: -17.762996335282654,
                 y: -0.5705853020142951,
             },
-            Coordinate {
+            Coord {
                 x: -14.686541886792453,
                 y: -0.6179386974271013,
             },
-            Coordinate {
+            Coord {
                 x: -14.16654566037736,
                 y: -0.4584178799313895,
             },
-            Coordinate {
+            Coord {
                 x: -12.238167249683144,
                 y: -0.10339011314667595,
             },
-            Coordinate {
+            Coord {
                 x: -12.048992598225603,
                 y: 0.09493810424780114,
             },
-            Coordinate {
+            Coord {
                 x: -11.434406533127888,
                 y: 0.548328214329738,
             },
-            Coordinate {
+            Coord {
                 x: -12.061923194795808,
                 y: 1.0096926760291751,
             },
-            Coordinate {
+            Coord {
                 x: -12.252020391300062,
                 y: 1.3257203411637153,
             },
-            Coordinate {
+            Coord {
                 x: -13.273099989518553,
                 y: 1.9496852820906998,
             },
-            Coordinate {
+            Coord {
                 x: -13.63667804765565,
                 y: 2.156483744811683,
             },
-            Coordinate {
+            Coord {
                 x: -14.98761148347425,
                 y: 3.0072801050241074,
             },
-            Coordinate {
+            Coord {
                 x: -16.31290654056326,
                 y: 3.7327158415300543,
             },
-            Coordinate {
+            Coord {
                 x: -17.47047051702396,
                 y: 4.467765943979518,
             },
-            Coordinate {
+            Coord {
                 x: -18.805969032258066,
                 y: 5.2491379914279275,
             },
-            Coordinate {
+            Coord {
                 x: -19.744846413264153,
                 y: 5.8500657829912015,
             },
-            Coordinate {
+            Coord {
                 x: -21.313840310763783,
                 y: 6.828671995115697,
             },
-            Coordinate {
+            Coord {
                 x: -21.760720188045667,
                 y: 7.283062592832285,
             },
-            Coordinate {
+            Coord {
                 x: -23.578762207792206,
                 y: 9.208967285714282,
             },
-            Coordinate {
+            Coord {
                 x: -24.13456233057851,
                 y: 9.8310492446281,
             },
-            Coordinate {
+            Coord {
                 x: -24.173934264159527,
                 y: 9.956412491091061,
             },
-            Coordinate {
+            Coord {
                 x: -24.92816475244362,
                 y: 12.659147962052003,
             },
-            Coordinate {
+            Coord {
                 x: -24.603885064377682,
                 y: 16.474625250357654,
             },
-            Coordinate {
+            Coord {
                 x: -23.740258135487103,
                 y: 18.613442011019284,
             },
-            Coordinate {
+            Coord {
                 x: -23.399807866462012,
                 y: 19.482226362240983,
             },
-            Coordinate {
+            Coord {
                 x: -22.718016916167663,
                 y: 21.438030538922153,
             },
-            Coordinate {
+            Coord {
                 x: -22.396746209856232,
                 y: 22.45136526357199,
             },
-            Coordinate {
+            Coord {
                 x: -22.331516400274786,
                 y: 23.55394084802687,
             },
-            Coordinate {
+            Coord {
                 x: -22.22993375,
                 y: 25.29813125,
             },
-            Coordinate {
+            Coord {
                 x: -22.386249915966385,
                 y: 25.92412142857143,
             },
-            Coordinate {
+            Coord {
                 x: -22.587118661087864,
                 y: 26.794778870292888,
             },
-            Coordinate {
+            Coord {
                 x: -22.910558189581554,
                 y: 27.39731293766012,
             },
-            Coordinate {
+            Coord {
                 x: -23.344000996369815,
                 y: 28.19831873406967,
             },
-            Coordinate {
+            Coord {
                 x: -23.9375303030303,
                 y: 28.77823205128205,
             },
-            Coordinate {
+            Coord {
                 x: -24.60429159010601,
                 y: 29.44911819787986,
             },
-            Coordinate {
+            Coord {
                 x: -25.371660437956205,
                 y: 29.835370437956204,
             },
-            Coordinate {
+            Coord {
                 x: -26.243404716636196,
                 y: 30.25297851919561,
             },
-            Coordinate {
+            Coord {
                 x: -26.961428682745826,
                 y: 30.306906586270873,
             },
-            Coordinate {
+            Coord {
                 x: -27.790137198879552,
                 y: 30.359385294117647,
             },
-            Coordinate {
+            Coord {
                 x: -28.696997658536585,
                 y: 30.2022816097561,
             },
-            Coordinate {
+            Coord {
                 x: -29.682429773844643,
                 y: 29.995819911504427,
             },
-            Coordinate {
+            Coord {
                 x: -30.449222558139535,
                 y: 29.55751353065539,
             },
-            Coordinate {
+            Coord {
                 x: -31.127445396825397,
                 y: 29.0939826984127,
             },
-            Coordinate {
+            Coord {
                 x: -31.647989944506104,
                 y: 28.46753923418424,
             },
-            Coordinate {
+            Coord {
                 x: -32.04125911111111,
                 y: 27.987764555555557,
             },
-            Coordinate {
+            Coord {
                 x: -32.50397153374233,
                 y: 26.902169754601225,
             },
-            Coordinate {
+            Coord {
                 x: -32.770865853658535,
                 y: 26.185701463414635,
             },
-            Coordinate {
+            Coord {
                 x: -32.82064685871056,
                 y: 25.020313511659808,
             },
-            Coordinate {
+            Coord {
                 x: -32.822309282814615,
                 y: 24.42639303112314,
             },
-            Coordinate {
+            Coord {
                 x: -32.50549139664804,
                 y: 23.599085754189943,
             },
-            Coordinate {
+            Coord {
                 x: -32.3978058336557,
                 y: 23.247991276595744,
             },
-            Coordinate {
+            Coord {
                 x: -31.36502830270793,
                 y: 22.035047872340424,
             },
-            Coordinate {
+            Coord {
                 x: -31.18816566676701,
                 y: 21.822496854304635,
             },
-            Coordinate {
+            Coord {
                 x: -30.96185148325359,
                 y: 21.70242033492823,
             },
-            Coordinate {
+            Coord {
                 x: -29.730719591836742,
                 y: 20.85393265306123,
             },
-            Coordinate {
+            Coord {
                 x: -29.64800517571885,
                 y: 20.79281517571885,
             },
-            Coordinate {
+            Coord {
                 x: -29.607291643192486,
                 y: 20.763275352112675,
             },
-            Coordinate {
+            Coord {
                 x: -28.08129243445693,
                 y: 20.45590767790262,
             },
-            Coordinate {
+            Coord {
                 x: -27.576188356807513,
                 y: 20.389938262910796,
             },
-            Coordinate {
+            Coord {
                 x: -26.504162092764382,
                 y: 20.757312857142853,
             },
-            Coordinate {
+            Coord {
                 x: -25.827658018867925,
                 y: 21.004933490566035,
             },
-            Coordinate {
+            Coord {
                 x: -25.563782351981352,
                 y: 21.153132307692307,
             },
-            Coordinate {
+            Coord {
                 x: -25.154486613891727,
                 y: 21.451698876404492,
             },
-            Coordinate {
+            Coord {
                 x: -25.502151275510204,
                 y: 21.09019107142857,
             },
-            Coordinate {
+            Coord {
                 x: -25.738428118096856,
                 y: 20.8454285046729,
             },
-            Coordinate {
+            Coord {
                 x: -26.892059487694624,
                 y: 20.369673756906078,
             },
-            Coordinate {
+            Coord {
                 x: -27.497449345794394,
                 y: 20.127992056074767,
             },
-            Coordinate {
+            Coord {
                 x: -29.683002025316448,
                 y: 20.240690506329113,
             },
-            Coordinate {
+            Coord {
                 x: -29.776196603773585,
                 y: 20.24805471698113,
             },
-            Coordinate {
+            Coord {
                 x: -29.898460881542707,
                 y: 20.314774242424246,
             },
-            Coordinate {
+            Coord {
                 x: -31.589991795454544,
                 y: 21.265352500000002,
             },
-            Coordinate {
+            Coord {
                 x: -31.84444385506773,
                 y: 21.56045053859964,
             },
-            Coordinate {
+            Coord {
                 x: -33.13351828571428,
                 y: 22.869603714285713,
             },
-            Coordinate {
+            Coord {
                 x: -33.55287204951857,
                 y: 23.805794841815683,
             },
-            Coordinate {
+            Coord {
                 x: -33.80227753087945,
                 y: 24.424874048913043,
             },
-            Coordinate {
+            Coord {
                 x: -33.80400857988815,
                 y: 25.554209589867313,
             },
-            Coordinate {
+            Coord {
                 x: -33.785873397713395,
                 y: 26.456192735042737,
             },
-            Coordinate {
+            Coord {
                 x: -33.40242480392157,
                 y: 27.417105882352942,
             },
-            Coordinate {
+            Coord {
                 x: -32.93022714775247,
                 y: 28.62972915742794,
             },
-            Coordinate {
+            Coord {
                 x: -32.384642582621794,
                 y: 29.308962707182317,
             },
-            Coordinate {
+            Coord {
                 x: -31.824917723444972,
                 y: 30.022619157894738,
             },
-            Coordinate {
+            Coord {
                 x: -30.77959560669456,
                 y: 30.667062343096234,
             },
-            Coordinate {
+            Coord {
                 x: -29.888071813755865,
                 y: 31.19783169425511,
             },
-            Coordinate {
+            Coord {
                 x: -28.65772803088803,
                 y: 31.405857335907335,
             },
-            Coordinate {
+            Coord {
                 x: -27.68799155940178,
                 y: 31.581126340110906,
             },
-            Coordinate {
+            Coord {
                 x: -26.925082752962627,
                 y: 31.5383112579763,
             },
-            Coordinate {
+            Coord {
                 x: -25.8657291598916,
                 y: 31.492427235772357,
             },
-            Coordinate {
+            Coord {
                 x: -25.01753990102961,
                 y: 31.07793937664618,
             },
-            Coordinate {
+            Coord {
                 x: -23.795313871965732,
                 y: 30.472989965095987,
             },
-            Coordinate {
+            Coord {
                 x: -23.07697220023015,
                 y: 29.828628396624474,
             },
-            Coordinate {
+            Coord {
                 x: -21.97976507346189,
                 y: 28.83267777777778,
             },
-            Coordinate {
+            Coord {
                 x: -21.6061247576761,
                 y: 28.170946688741722,
             },
-            Coordinate {
+            Coord {
                 x: -21.0327525807913,
                 y: 27.166926910299004,
             },
-            Coordinate {
+            Coord {
                 x: -20.820727428571427,
                 y: 26.474966408163265,
             },
-            Coordinate {
+            Coord {
                 x: -20.455786887481374,
                 y: 25.38585393442623,
             },
-            Coordinate {
+            Coord {
                 x: -20.477531548117156,
                 y: 23.447674351464435,
             },
-            Coordinate {
+            Coord {
                 x: -20.49829397324941,
                 y: 22.25549319433517,
             },
-            Coordinate {
+            Coord {
                 x: -20.929545207677094,
                 y: 20.23260777598711,
             },
-            Coordinate {
+            Coord {
                 x: -21.188684091258473,
                 y: 19.157146848578016,
             },
-            Coordinate {
+            Coord {
                 x: -21.77237597018182,
                 y: 17.063638,
             },
-            Coordinate {
+            Coord {
                 x: -22.03866094887179,
                 y: 16.13062137733142,
             },
-            Coordinate {
+            Coord {
                 x: -22.15582181126332,
                 y: 13.518502511415525,
             },
-            Coordinate {
+            Coord {
                 x: -22.18638919636618,
                 y: 12.702478860936408,
             },
-            Coordinate {
+            Coord {
                 x: -21.989489144542773,
                 y: 12.292867050147493,
             },
-            Coordinate {
+            Coord {
                 x: -21.114713748308528,
                 y: 10.608078822733424,
             },
-            Coordinate {
+            Coord {
                 x: -21.00603789029536,
                 y: 10.538736660529343,
             },
-            Coordinate {
+            Coord {
                 x: -20.42652575098814,
                 y: 10.175913671936756,
             },
-            Coordinate {
+            Coord {
                 x: -19.25886409028728,
                 y: 9.39700782862828,
             },
-            Coordinate {
+            Coord {
                 x: -19.012926060191518,
                 y: 9.224552410396717,
             },
-            Coordinate {
+            Coord {
                 x: -18.7936169680388,
                 y: 9.148506849023754,
             },
-            Coordinate {
+            Coord {
                 x: -17.236781365461848,
                 y: 8.895279670317635,
             },
-            Coordinate {
+            Coord {
                 x: -16.55242514056225,
                 y: 8.853177662285507,
             },
-            Coordinate {
+            Coord {
                 x: -15.286186615322254,
                 y: 8.762651024836938,
             },
-            Coordinate {
+            Coord {
                 x: -14.187620299959466,
                 y: 8.939444052806131,
             },
-            Coordinate {
+            Coord {
                 x: -12.969017033492824,
                 y: 8.966239602870813,
             },
-            Coordinate {
+            Coord {
                 x: -12.340177833783379,
                 y: 9.52010015926138,
             },
-            Coordinate {
+            Coord {
                 x: -10.472334455609197,
                 y: 10.554994374437443,
             },
-            Coordinate {
+            Coord {
                 x: -9.945373190300849,
                 y: 11.377743549354935,
             },
-            Coordinate {
+            Coord {
                 x: -9.726739964464851,
                 y: 12.371978245951139,
             },
-            Coordinate {
+            Coord {
                 x: -9.172063614403713,
                 y: 14.402422961844634,
             },
-            Coordinate {
+            Coord {
                 x: -8.913498413132695,
                 y: 15.498490355677156,
             },
-            Coordinate {
+            Coord {
                 x: -8.131291709986321,
                 y: 16.903737961696308,
             },
-            Coordinate {
+            Coord {
                 x: -7.552932397723902,
                 y: 17.946063616137646,
             },
-            Coordinate {
+            Coord {
                 x: -7.071593240203878,
                 y: 18.22610304064076,
             },
-            Coordinate {
+            Coord {
                 x: -6.05370751627385,
                 y: 19.13448091168091,
             },
-            Coordinate {
+            Coord {
                 x: -6.047818870620203,
                 y: 19.140960330361825,
             },
-            Coordinate {
+            Coord {
                 x: -6.044582331695332,
                 y: 19.14263108108108,
             },
-            Coordinate {
+            Coord {
                 x: -6.057877559139785,
                 y: 19.20112634408602,
             },
-            Coordinate {
+            Coord {
                 x: -6.346836413347981,
                 y: 20.49469259818731,
             },
-            Coordinate {
+            Coord {
                 x: -6.070152605415861,
                 y: 23.854260638297873,
             },
-            Coordinate {
+            Coord {
                 x: -6.063316796905222,
                 y: 23.97553936170213,
             },
-            Coordinate {
+            Coord {
                 x: -5.398866444444451,
                 y: 26.473267777777757,
             },
-            Coordinate {
+            Coord {
                 x: -5.37000798303737,
                 y: 26.57189839650146,
             },
-            Coordinate {
+            Coord {
                 x: -5.367740358768007,
                 y: 26.575741803278692,
             },
-            Coordinate {
+            Coord {
                 x: -3.9538550711382108,
                 y: 28.821279878048777,
             },
-            Coordinate {
+            Coord {
                 x: -3.842939595480225,
                 y: 28.956972372881356,
             },
-            Coordinate {
+            Coord {
                 x: -2.28435846031746,
                 y: 30.68602439153439,
             },
-            Coordinate {
+            Coord {
                 x: -2.0441190693834885,
                 y: 30.94827842215256,
             },
-            Coordinate {
+            Coord {
                 x: -0.07693699236901018,
                 y: 32.52823669950739,
             },
-            Coordinate {
+            Coord {
                 x: 1.5832581253109121,
                 y: 33.44101878612717,
             },
-            Coordinate {
+            Coord {
                 x: 2.0860712304687503,
                 y: 33.759799609375,
             },
-            Coordinate {
+            Coord {
                 x: 3.6439736933461906,
                 y: 35.11893457087753,
             },
-            Coordinate {
+            Coord {
                 x: 4.102558532721655,
                 y: 35.52245731204943,
             },
-            Coordinate {
+            Coord {
                 x: 4.923202312523191,
                 y: 36.57459959183674,
             },
-            Coordinate {
+            Coord {
                 x: 5.0777096894409945,
                 y: 36.98563260869565,
             },
-            Coordinate {
+            Coord {
                 x: 5.167354957983194,
                 y: 37.36068361344538,
             },
-            Coordinate {
+            Coord {
                 x: 5.422091373936874,
                 y: 38.39082692307692,
             },
-            Coordinate {
+            Coord {
                 x: 5.371153718843471,
                 y: 40.04495299102692,
             },
-            Coordinate {
+            Coord {
                 x: 5.338304810491028,
                 y: 40.827338286334054,
             },
-            Coordinate {
+            Coord {
                 x: 4.620550644285577,
                 y: 42.290590807651434,
             },
-            Coordinate {
+            Coord {
                 x: 4.2272248334521025,
                 y: 43.05065078387458,
             },
-            Coordinate {
+            Coord {
                 x: 3.0952544545454552,
                 y: 43.856669696969696,
             },
-            Coordinate {
+            Coord {
                 x: 2.4151309047213045,
                 y: 44.36484906213364,
             },
-            Coordinate {
+            Coord {
                 x: 1.3799379064969368,
                 y: 44.57816889035667,
             },
-            Coordinate {
+            Coord {
                 x: 0.2030001087504256,
                 y: 44.807329026217225,
             },
-            Coordinate {
+            Coord {
                 x: -1.014351625231912,
                 y: 44.62444416909621,
             },
-            Coordinate {
+            Coord {
                 x: -1.8792688937329702,
                 y: 44.47033828337875,
             },
-            Coordinate {
+            Coord {
                 x: -2.865053340354768,
                 y: 43.91641189024391,
             },
-            Coordinate {
+            Coord {
                 x: -3.3367251252144086,
                 y: 43.648459433962266,
             },
-            Coordinate {
+            Coord {
                 x: -4.163782016583749,
                 y: 42.74171782752902,
             },
-            Coordinate {
+            Coord {
                 x: -4.387177230293664,
                 y: 42.48452557959815,
             },
-            Coordinate {
+            Coord {
                 x: -4.750206533980583,
                 y: 41.464914563106795,
             },
-            Coordinate {
+            Coord {
                 x: -4.831281000000001,
                 y: 41.275040393700785,
             },
-            Coordinate {
+            Coord {
                 x: -4.874867413342054,
                 y: 39.75972913669065,
             },
-            Coordinate {
+            Coord {
                 x: -4.983139000000002,
                 y: 39.779250000000005,
             },
-            Coordinate {
+            Coord {
                 x: -5.6213299400386845,
                 y: 39.61569893617022,
             },
-            Coordinate {
+            Coord {
                 x: -5.640739397653959,
                 y: 41.365090645161295,
             },
-            Coordinate {
+            Coord {
                 x: -5.54123770967742,
                 y: 41.69300419354839,
             },
-            Coordinate {
+            Coord {
                 x: -5.2052024690019465,
                 y: 42.99475733944954,
             },
-            Coordinate {
+            Coord {
                 x: -4.936675558271237,
                 y: 43.355789016393445,
             },
-            Coordinate {
+            Coord {
                 x: -4.133007236051502,
                 y: 44.43872639484979,
             },
-            Coordinate {
+            Coord {
                 x: -3.5600852883869187,
                 y: 44.86291890243902,
             },
-            Coordinate {
+            Coord {
                 x: -2.5856937695818427,
                 y: 45.581317571234734,
             },
-            Coordinate {
+            Coord {
                 x: -1.2322711190909104,
                 y: 45.93006,
             },
-            Coordinate {
+            Coord {
                 x: 0.1608385382727273,
                 y: 46.259575,
             },
-            Coordinate {
+            Coord {
                 x: 1.7365781648429088,
                 y: 46.00393449408673,
             },
-            Coordinate {
+            Coord {
                 x: 2.8468431018518516,
                 y: 45.86612743055555,
             },
-            Coordinate {
+            Coord {
                 x: 4.256296545562899,
                 y: 44.89123009535161,
             },
-            Coordinate {
+            Coord {
                 x: 4.995079966769783,
                 y: 44.38911180021954,
             },
-            Coordinate {
+            Coord {
                 x: 6.380004119331006,
                 y: 42.54066106094808,
             },
-            Coordinate {
+            Coord {
                 x: 6.952215063811189,
                 y: 41.74905192307692,
             },
-            Coordinate {
+            Coord {
                 x: 7.349461253825382,
                 y: 39.13993118811881,
             },
-            Coordinate {
+            Coord {
                 x: 7.490684559925788,
                 y: 38.2612581632653,
             },
-            Coordinate {
+            Coord {
                 x: 7.423452432835821,
                 y: 37.77020880597015,
             },
-            Coordinate {
+            Coord {
                 x: 7.202182780487805,
                 y: 36.0074131097561,
             },
-            Coordinate {
+            Coord {
                 x: 6.960150369047618,
                 y: 35.57710565476191,
             },
-            Coordinate {
+            Coord {
                 x: 6.101460821862348,
                 y: 33.83537813765182,
             },
-            Coordinate {
+            Coord {
                 x: 5.62992931724461,
                 y: 33.371372164948454,
             },
-            Coordinate {
+            Coord {
                 x: 3.8474323207649777,
                 y: 31.76261410767697,
             },
-            Coordinate {
+            Coord {
                 x: 2.056944251000573,
                 y: 30.584706603773586,
             },
-            Coordinate {
+            Coord {
                 x: 1.6163872697599135,
                 y: 30.26859866468843,
             },
-            Coordinate {
+            Coord {
                 x: 1.3550732860903671,
                 y: 29.94790892857143,
             },
-            Coordinate {
+            Coord {
                 x: 0.4001953975927917,
                 y: 28.697926627218937,
             },
-            Coordinate {
+            Coord {
                 x: -0.36884680624752175,
                 y: 27.03094937694704,
             },
-            Coordinate {
+            Coord {
                 x: -0.5169385745180364,
                 y: 26.724205607917057,
             },
-            Coordinate {
+            Coord {
                 x: -0.5741684070001553,
                 y: 26.454760562180578,
             },
-            Coordinate {
+            Coord {
                 x: -0.8981726969152933,
                 y: 24.49638052064632,
             },
-            Coordinate {
+            Coord {
                 x: -0.8786989969234652,
                 y: 24.347400762829405,
             },
-            Coordinate {
+            Coord {
                 x: -0.44431434975902834,
                 y: 22.374085657225855,
             },
-            Coordinate {
+            Coord {
                 x: -0.06148776779719839,
                 y: 21.08726538461537,
             },
@@ -4001,4 +4005,2141 @@ mod test {
         let collated_into = layer.collate_into().unwrap();
         assert!(multi_polygons_equivalent(&collated, &collated_into));
     }
+
+    #[test]
+    fn square_hole_reversed_winding() {
+        let exterior: LineString<f64> =
+            vec![(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (0.0, 3.0), (0.0, 0.0)].into();
+        let hole: LineString<f64> =
+            vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0), (1.0, 1.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![exterior, hole]).into_iter().collect();
+        let collated = uncollated.collate().unwrap();
+
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior(), &uncollated.0[0]);
+        assert_eq!(collated.0[0].interiors(), &uncollated.0[1..]);
+
+        let collated_into = uncollated.clone().collate_into().unwrap();
+        assert!(multi_polygons_equivalent(&collated, &collated_into));
+
+        assert!(uncollated.collate_oriented().is_err());
+        assert!(uncollated.collate_into_oriented().is_err());
+    }
+
+    #[test]
+    fn poly_in_hole_mixed_winding_int() {
+        let exterior1: LineString<i64> = vec![(0, 0), (0, 60), (60, 60), (60, 0), (0, 0)].into();
+        let hole1: LineString<i64> = vec![(10, 10), (10, 50), (50, 50), (50, 10), (10, 10)].into();
+        let exterior2: LineString<i64> =
+            vec![(20, 20), (40, 20), (40, 40), (20, 40), (20, 20)].into();
+        let hole2: LineString<i64> = vec![(25, 25), (25, 35), (35, 35), (35, 25), (25, 25)].into();
+
+        let uncollated: MultiLineString<i64> = (vec![exterior1, hole1, exterior2, hole2])
+            .into_iter()
+            .collect();
+        let collated = uncollated.collate().unwrap();
+
+        assert_eq!(collated.0.len(), 2);
+        assert_eq!(collated.0[0].interiors().len(), 1);
+        assert_eq!(collated.0[1].interiors().len(), 1);
+
+        let collated_into = uncollated.clone().collate_into().unwrap();
+        assert!(multi_polygons_equivalent(&collated, &collated_into));
+
+        assert!(uncollated.collate_oriented().is_err());
+    }
+
+    #[test]
+    fn oriented_matches_nesting() {
+        let exterior: LineString<i64> = vec![(0, 0), (0, 30), (30, 30), (30, 0), (0, 0)].into();
+        let hole: LineString<i64> = vec![(10, 10), (20, 10), (20, 20), (10, 20), (10, 10)].into();
+        let uncollated: MultiLineString<i64> = (vec![exterior, hole]).into_iter().collect();
+
+        let collated = uncollated.collate().unwrap();
+        let collated_oriented = uncollated.collate_oriented().unwrap();
+        assert!(multi_polygons_equivalent(&collated, &collated_oriented));
+
+        let collated_into_oriented = uncollated.collate_into_oriented().unwrap();
+        assert!(multi_polygons_equivalent(
+            &collated,
+            &collated_into_oriented
+        ));
+    }
+
+    #[test]
+    fn crossing_rings_int() {
+        let ring1: LineString<i64> = vec![(0, 0), (0, 20), (20, 20), (20, 0), (0, 0)].into();
+        let ring2: LineString<i64> = vec![(10, 5), (10, 15), (30, 15), (30, 5), (10, 5)].into();
+        let uncollated: MultiLineString<i64> = (vec![ring1, ring2]).into_iter().collect();
+
+        match uncollated.collate() {
+            Err(CollateError::RingsCross { .. }) => {}
+            other => panic!("expected crossing rings, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn winding_counter_clockwise() {
+        let exterior1: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 6.0), (6.0, 6.0), (6.0, 0.0), (0.0, 0.0)].into();
+        let hole1: LineString<f64> =
+            vec![(1.0, 1.0), (1.0, 5.0), (5.0, 5.0), (5.0, 1.0), (1.0, 1.0)].into();
+        let exterior2: LineString<f64> =
+            vec![(2.0, 2.0), (4.0, 2.0), (4.0, 4.0), (2.0, 4.0), (2.0, 2.0)].into();
+        let hole2: LineString<f64> =
+            vec![(2.5, 2.5), (3.5, 2.5), (3.5, 3.5), (2.5, 3.5), (2.5, 2.5)].into();
+
+        let uncollated: MultiLineString<f64> = (vec![exterior1, hole1, exterior2, hole2])
+            .into_iter()
+            .collect();
+        let collated = uncollated
+            .collate_with_winding(Winding::CounterClockwise)
+            .unwrap();
+
+        assert_eq!(collated.0.len(), 2);
+        for poly in collated.0.iter() {
+            assert!(poly.exterior().is_ccw());
+            assert!(poly.interiors().iter().all(|hole| hole.is_cw()));
+        }
+
+        let collated_into = uncollated
+            .collate_into_with_winding(Winding::CounterClockwise)
+            .unwrap();
+        assert!(multi_polygons_equivalent(&collated, &collated_into));
+    }
+
+    #[test]
+    fn winding_clockwise_int() {
+        let exterior: LineString<i64> = vec![(0, 0), (30, 0), (30, 30), (0, 30), (0, 0)].into();
+        let hole: LineString<i64> = vec![(10, 10), (20, 10), (20, 20), (10, 20), (10, 10)].into();
+        let uncollated: MultiLineString<i64> = (vec![exterior, hole]).into_iter().collect();
+
+        let collated = uncollated.collate_with_winding(Winding::Clockwise).unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert!(collated.0[0].exterior().is_cw());
+        assert!(collated.0[0].interiors()[0].is_ccw());
+
+        let preserved = uncollated.collate_with_winding(Winding::Preserve).unwrap();
+        assert!(preserved.0[0].exterior().is_ccw());
+        assert!(preserved.0[0].interiors()[0].is_ccw());
+
+        let collated_into = uncollated
+            .collate_into_with_winding(Winding::Clockwise)
+            .unwrap();
+        assert!(multi_polygons_equivalent(&collated, &collated_into));
+    }
+
+    #[test]
+    fn degenerate_rings_policy_int() {
+        let exterior: LineString<i64> = vec![(0, 0), (0, 30), (30, 30), (30, 0), (0, 0)].into();
+        let sliver: LineString<i64> = vec![(40, 0), (40, 10), (40, 0)].into();
+        let flat: LineString<i64> = vec![(50, 0), (60, 0), (70, 0), (50, 0)].into();
+        let uncollated: MultiLineString<i64> = (vec![exterior, sliver, flat]).into_iter().collect();
+
+        let options = CollateOptions::new().degenerate_rings(DegenerateRings::Skip);
+        let collated = uncollated.collate_with(&options).unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior(), &uncollated.0[0]);
+
+        let collated_into = uncollated.clone().collate_into_with(&options).unwrap();
+        assert!(multi_polygons_equivalent(&collated, &collated_into));
+
+        let options = CollateOptions::new().degenerate_rings(DegenerateRings::Reject);
+        match uncollated.collate_with(&options) {
+            Err(CollateError::DegenerateRing { ring: 1 }) => {}
+            other => panic!("expected degenerate ring 1, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn collate_with_options() {
+        let exterior: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 3.0), (3.0, 3.0), (3.0, 0.0), (0.0, 0.0)].into();
+        let hole: LineString<f64> =
+            vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0), (1.0, 1.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![exterior, hole]).into_iter().collect();
+
+        let options = CollateOptions::new()
+            .orientation(OrientationPolicy::Enforce)
+            .winding(Winding::CounterClockwise);
+        let collated = uncollated.collate_with(&options).unwrap();
+
+        assert_eq!(collated.0.len(), 1);
+        assert!(collated.0[0].exterior().is_ccw());
+        assert!(collated.0[0].interiors()[0].is_cw());
+
+        let collated_into = uncollated.clone().collate_into_with(&options).unwrap();
+        assert!(multi_polygons_equivalent(&collated, &collated_into));
+
+        let defaults = uncollated.collate_with(&CollateOptions::default()).unwrap();
+        assert!(multi_polygons_equivalent(
+            &defaults,
+            &uncollated.collate().unwrap()
+        ));
+    }
+
+    #[test]
+    fn collation_tree_poly_in_hole() {
+        let exterior1: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 6.0), (6.0, 6.0), (6.0, 0.0), (0.0, 0.0)].into();
+        let hole1: LineString<f64> =
+            vec![(1.0, 1.0), (5.0, 1.0), (5.0, 5.0), (1.0, 5.0), (1.0, 1.0)].into();
+        let exterior2: LineString<f64> =
+            vec![(2.0, 2.0), (2.0, 4.0), (4.0, 4.0), (4.0, 2.0), (2.0, 2.0)].into();
+        let hole2: LineString<f64> =
+            vec![(2.5, 2.5), (3.5, 2.5), (3.5, 3.5), (2.5, 3.5), (2.5, 2.5)].into();
+
+        let uncollated: MultiLineString<f64> = (vec![hole2, exterior2, hole1, exterior1])
+            .into_iter()
+            .collect();
+        let tree = uncollated.collation_tree().unwrap();
+
+        assert_eq!(tree.len(), 4);
+        assert_eq!(tree.roots(), &[3]);
+        assert_eq!(tree.parent(3), None);
+        assert_eq!(tree.parent(2), Some(3));
+        assert_eq!(tree.parent(1), Some(2));
+        assert_eq!(tree.parent(0), Some(1));
+        assert_eq!(tree.children(3), &[2]);
+        assert_eq!(tree.children(0), &[] as &[usize]);
+        assert_eq!(
+            (0..4).map(|i| tree.depth(i)).collect::<Vec<_>>(),
+            vec![Some(3), Some(2), Some(1), Some(0)]
+        );
+        assert!(tree.is_exterior(1));
+        assert!(tree.is_hole(0));
+
+        assert_eq!(tree.outermost_first().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert_eq!(tree.innermost_first().collect::<Vec<_>>(), vec![0, 1, 2, 3]);
+    }
+
+    #[test]
+    fn collation_tree_siblings_int() {
+        let exterior1: LineString<i64> = vec![(0, 0), (0, 30), (30, 30), (30, 0), (0, 0)].into();
+        let hole1: LineString<i64> = vec![(10, 10), (20, 10), (20, 20), (10, 20), (10, 10)].into();
+        let exterior2: LineString<i64> = exterior1.translate(40, 0);
+        let hole2: LineString<i64> = hole1.translate(40, 0);
+        let sliver: LineString<i64> = vec![(100, 0), (100, 10), (100, 0)].into();
+
+        let uncollated: MultiLineString<i64> = (vec![exterior1, hole1, exterior2, hole2, sliver])
+            .into_iter()
+            .collect();
+        let options = CollateOptions::new().degenerate_rings(DegenerateRings::Skip);
+        let tree = uncollated.collation_tree_with(&options).unwrap();
+
+        assert_eq!(tree.roots(), &[0, 2]);
+        assert_eq!(tree.children(0), &[1]);
+        assert_eq!(tree.children(2), &[3]);
+        assert_eq!(tree.depth(4), None);
+        assert_eq!(tree.parent(4), None);
+        assert!(!tree.is_exterior(4) && !tree.is_hole(4));
+
+        let outermost = tree.outermost_first().collect::<Vec<_>>();
+        assert_eq!(outermost, vec![0, 2, 1, 3]);
+    }
+
+    #[test]
+    fn collate_indices_poly_in_hole() {
+        let exterior1: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 6.0), (6.0, 6.0), (6.0, 0.0), (0.0, 0.0)].into();
+        let hole1: LineString<f64> =
+            vec![(1.0, 1.0), (5.0, 1.0), (5.0, 5.0), (1.0, 5.0), (1.0, 1.0)].into();
+        let exterior2: LineString<f64> =
+            vec![(2.0, 2.0), (2.0, 4.0), (4.0, 4.0), (4.0, 2.0), (2.0, 2.0)].into();
+        let hole2: LineString<f64> =
+            vec![(2.5, 2.5), (3.5, 2.5), (3.5, 3.5), (2.5, 3.5), (2.5, 2.5)].into();
+
+        let uncollated: MultiLineString<f64> = (vec![hole1, exterior2, exterior1, hole2])
+            .into_iter()
+            .collect();
+        let indices = uncollated.collate_indices().unwrap();
+
+        assert_eq!(
+            indices,
+            vec![
+                PolygonIndices {
+                    exterior: 1,
+                    interiors: vec![3],
+                },
+                PolygonIndices {
+                    exterior: 2,
+                    interiors: vec![0],
+                },
+            ]
+        );
+
+        let collated = uncollated.collate().unwrap();
+        let rebuilt = MultiPolygon(
+            indices
+                .iter()
+                .map(|poly| {
+                    Polygon::new(
+                        uncollated.0[poly.exterior].clone(),
+                        poly.interiors
+                            .iter()
+                            .map(|i| uncollated.0[*i].clone())
+                            .collect(),
+                    )
+                })
+                .collect(),
+        );
+        assert!(multi_polygons_equivalent(&collated, &rebuilt));
+    }
+
+    #[test]
+    fn collate_indices_two_holes_int() {
+        let hole1: LineString<i64> = vec![(10, 10), (20, 10), (20, 20), (10, 20), (10, 10)].into();
+        let hole2: LineString<i64> = vec![(30, 10), (40, 10), (40, 20), (30, 20), (30, 10)].into();
+        let exterior: LineString<i64> = vec![(0, 0), (0, 60), (60, 60), (60, 0), (0, 0)].into();
+        let uncollated: MultiLineString<i64> = (vec![hole2, exterior, hole1]).into_iter().collect();
+
+        let indices = uncollated.collate_indices().unwrap();
+        assert_eq!(indices.len(), 1);
+        assert_eq!(indices[0].exterior, 1);
+        assert_eq!(indices[0].interiors, vec![0, 2]);
+    }
+
+    #[test]
+    fn deterministic_order() {
+        let small: LineString<f64> = vec![
+            (10.0, 0.0),
+            (10.0, 1.0),
+            (11.0, 1.0),
+            (11.0, 0.0),
+            (10.0, 0.0),
+        ]
+        .into();
+        let big: LineString<f64> =
+            vec![(0.0, 5.0), (0.0, 9.0), (4.0, 9.0), (4.0, 5.0), (0.0, 5.0)].into();
+        let hole2: LineString<f64> =
+            vec![(2.0, 6.0), (3.0, 6.0), (3.0, 7.0), (2.0, 7.0), (2.0, 6.0)].into();
+        let medium: LineString<f64> =
+            vec![(5.0, 0.0), (5.0, 2.0), (7.0, 2.0), (7.0, 0.0), (5.0, 0.0)].into();
+        let hole1: LineString<f64> =
+            vec![(1.0, 6.0), (1.5, 6.0), (1.5, 7.0), (1.0, 7.0), (1.0, 6.0)].into();
+
+        let uncollated: MultiLineString<f64> = (vec![small, big, hole2, medium, hole1])
+            .into_iter()
+            .collect();
+
+        for _ in 0..8 {
+            let collated = uncollated.collate().unwrap();
+            let exteriors: Vec<_> = collated.0.iter().map(|p| p.exterior().clone()).collect();
+            assert_eq!(
+                exteriors,
+                vec![
+                    uncollated.0[0].clone(),
+                    uncollated.0[1].clone(),
+                    uncollated.0[3].clone()
+                ]
+            );
+            assert_eq!(
+                collated.0[1].interiors(),
+                &[uncollated.0[2].clone(), uncollated.0[4].clone()]
+            );
+        }
+
+        let by_area = uncollated
+            .collate_indices_with(&CollateOptions::new().order(PolygonOrder::Area))
+            .unwrap();
+        assert_eq!(
+            by_area.iter().map(|p| p.exterior).collect::<Vec<_>>(),
+            vec![1, 3, 0]
+        );
+
+        let by_bbox = CollateOptions::new().order(PolygonOrder::BoundingBox);
+        let indices = uncollated.collate_indices_with(&by_bbox).unwrap();
+        assert_eq!(
+            indices.iter().map(|p| p.exterior).collect::<Vec<_>>(),
+            vec![3, 0, 1]
+        );
+
+        let collated = uncollated.collate_with(&by_bbox).unwrap();
+        assert_eq!(collated.0[0].exterior(), &uncollated.0[3]);
+        let collated_into = uncollated.clone().collate_into_with(&by_bbox).unwrap();
+        assert_eq!(collated, collated_into);
+    }
+
+    #[test]
+    fn crossing_rings_diagnostics_int() {
+        let ring1: LineString<i64> = vec![(0, 0), (0, 20), (20, 20), (20, 0), (0, 0)].into();
+        let ring2: LineString<i64> = vec![(10, 5), (10, 15), (30, 15), (30, 5), (10, 5)].into();
+        let uncollated: MultiLineString<i64> = (vec![ring1, ring2]).into_iter().collect();
+
+        let err = uncollated.collate().unwrap_err();
+        assert_eq!(
+            err,
+            CollateError::RingsCross {
+                ring: 0,
+                neighbour: 1,
+                sweep: 5,
+                x: 20,
+            }
+        );
+        assert_eq!(err.rings(), vec![0, 1]);
+        assert_eq!(err.to_string(), "Ring 0 crosses ring 1 at sweep 5 20");
+    }
+
+    #[test]
+    fn orientation_diagnostics() {
+        let exterior: LineString<f64> =
+            vec![(0.0, 0.0), (3.0, 0.0), (3.0, 3.0), (0.0, 3.0), (0.0, 0.0)].into();
+        let hole: LineString<f64> =
+            vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0), (1.0, 1.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![exterior.clone(), hole]).into_iter().collect();
+
+        assert_eq!(
+            uncollated.collate_oriented().unwrap_err(),
+            CollateError::HoleWithoutOutline {
+                ring: 0,
+                neighbour: None,
+                sweep: 0.0,
+                x: 0.0,
+            }
+        );
+
+        let outer: LineString<f64> = vec![
+            (-1.0, -1.0),
+            (-1.0, 4.0),
+            (4.0, 4.0),
+            (4.0, -1.0),
+            (-1.0, -1.0),
+        ]
+        .into();
+        let inner: LineString<f64> = exterior.0.into_iter().rev().collect();
+        let uncollated: MultiLineString<f64> = (vec![inner, outer]).into_iter().collect();
+
+        let err = uncollated.collate_oriented().unwrap_err();
+        assert_eq!(
+            err,
+            CollateError::OutlineInOutline {
+                ring: 0,
+                neighbour: 1,
+                sweep: 0.0,
+                x: 0.0,
+            }
+        );
+        assert_eq!(err.rings(), vec![0, 1]);
+    }
+
+    #[test]
+    fn lenient_rejects_crossing_rings_int() {
+        let exterior: LineString<i64> = vec![(0, 0), (0, 30), (30, 30), (30, 0), (0, 0)].into();
+        let hole: LineString<i64> = vec![(10, 10), (20, 10), (20, 20), (10, 20), (10, 10)].into();
+        let crossing1: LineString<i64> = vec![(40, 0), (40, 20), (60, 20), (60, 0), (40, 0)].into();
+        let crossing2: LineString<i64> = vec![(50, 5), (50, 15), (70, 15), (70, 5), (50, 5)].into();
+        let inside_crossing: LineString<i64> =
+            vec![(42, 8), (42, 12), (46, 12), (46, 8), (42, 8)].into();
+
+        let uncollated: MultiLineString<i64> =
+            (vec![exterior, hole, crossing1, crossing2, inside_crossing])
+                .into_iter()
+                .collect();
+        assert!(uncollated.collate().is_err());
+
+        let (collated, issues) = uncollated.collate_lenient();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior(), &uncollated.0[0]);
+        assert_eq!(collated.0[0].interiors(), &uncollated.0[1..2]);
+
+        assert_eq!(
+            issues.iter().map(|issue| issue.ring).collect::<Vec<_>>(),
+            vec![2, 3, 4]
+        );
+        assert!(matches!(issues[0].error, CollateError::RingsCross { .. }));
+        assert_eq!(
+            issues[2].error,
+            CollateError::InsideRejectedRing {
+                ring: 4,
+                neighbour: 2
+            }
+        );
+    }
+
+    #[test]
+    fn lenient_matches_strict_on_valid_input() {
+        let exterior1: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 6.0), (6.0, 6.0), (6.0, 0.0), (0.0, 0.0)].into();
+        let hole1: LineString<f64> =
+            vec![(1.0, 1.0), (5.0, 1.0), (5.0, 5.0), (1.0, 5.0), (1.0, 1.0)].into();
+        let exterior2: LineString<f64> =
+            vec![(2.0, 2.0), (2.0, 4.0), (4.0, 4.0), (4.0, 2.0), (2.0, 2.0)].into();
+        let sliver: LineString<f64> = vec![(8.0, 0.0), (8.0, 1.0), (8.0, 0.0)].into();
+
+        let uncollated: MultiLineString<f64> = (vec![exterior1, hole1, exterior2, sliver])
+            .into_iter()
+            .collect();
+        let options = CollateOptions::new().degenerate_rings(DegenerateRings::Reject);
+
+        let (collated, issues) = uncollated.collate_lenient_with(&options);
+        assert_eq!(
+            issues,
+            vec![CollateIssue {
+                ring: 3,
+                error: CollateError::DegenerateRing { ring: 3 },
+            }]
+        );
+
+        let options = options.degenerate_rings(DegenerateRings::Skip);
+        assert_eq!(collated, uncollated.collate_with(&options).unwrap());
+    }
+
+    #[test]
+    fn empty_input() {
+        let uncollated: MultiLineString<f64> = MultiLineString(vec![]);
+        assert_eq!(uncollated.collate().unwrap(), MultiPolygon(vec![]));
+        assert_eq!(uncollated.collate_indices().unwrap(), vec![]);
+        assert!(uncollated.collation_tree().unwrap().is_empty());
+        assert_eq!(uncollated.collate_lenient(), (MultiPolygon(vec![]), vec![]));
+        assert_eq!(uncollated.collate_into().unwrap(), MultiPolygon(vec![]));
+
+        let uncollated: MultiLineString<i64> = MultiLineString(vec![]);
+        assert_eq!(uncollated.collate().unwrap(), MultiPolygon(vec![]));
+    }
+
+    #[test]
+    fn empty_ring_int() {
+        let exterior: LineString<i64> = vec![(0, 0), (0, 30), (30, 30), (30, 0), (0, 0)].into();
+        let uncollated: MultiLineString<i64> =
+            (vec![exterior, LineString(vec![])]).into_iter().collect();
+
+        assert_eq!(
+            uncollated.collate().unwrap_err(),
+            CollateError::DegenerateRing { ring: 1 }
+        );
+
+        let options = CollateOptions::new().degenerate_rings(DegenerateRings::Skip);
+        assert_eq!(uncollated.collate_with(&options).unwrap().0.len(), 1);
+
+        let (collated, issues) = uncollated.collate_lenient();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].ring, 1);
+    }
+
+    #[test]
+    fn non_finite_coordinates() {
+        let exterior: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 3.0), (3.0, 3.0), (3.0, 0.0), (0.0, 0.0)].into();
+        let nan: LineString<f64> = vec![
+            (5.0, 0.0),
+            (5.0, 1.0),
+            (f64::NAN, 1.0),
+            (6.0, 0.0),
+            (5.0, 0.0),
+        ]
+        .into();
+        let infinite: LineString<f64> = vec![
+            (8.0, 0.0),
+            (8.0, f64::INFINITY),
+            (9.0, 1.0),
+            (9.0, 0.0),
+            (8.0, 0.0),
+        ]
+        .into();
+        let uncollated: MultiLineString<f64> =
+            (vec![exterior, nan, infinite]).into_iter().collect();
+
+        assert_eq!(
+            uncollated.collate().unwrap_err(),
+            CollateError::NonFinite { ring: 1, vertex: 2 }
+        );
+        assert!(uncollated.clone().collate_into().is_err());
+        assert!(uncollated.collation_tree().is_err());
+
+        let (collated, issues) = uncollated.collate_lenient();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(
+            issues,
+            vec![
+                CollateIssue {
+                    ring: 1,
+                    error: CollateError::NonFinite { ring: 1, vertex: 2 },
+                },
+                CollateIssue {
+                    ring: 2,
+                    error: CollateError::NonFinite { ring: 2, vertex: 1 },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn open_rings_policy() {
+        let exterior: LineString<f64> = vec![(0.0, 0.0), (0.0, 3.0), (3.0, 3.0), (3.0, 0.0)].into();
+        let hole: LineString<f64> =
+            vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0), (1.0, 1.1)].into();
+        let uncollated: MultiLineString<f64> = (vec![exterior, hole]).into_iter().collect();
+
+        assert_eq!(
+            uncollated.collate().unwrap_err(),
+            CollateError::OpenRing {
+                ring: 0,
+                start: (0.0, 0.0),
+                end: (3.0, 0.0),
+            }
+        );
+
+        let options = CollateOptions::new().open_rings(OpenRings::Close);
+        let collated = uncollated.collate_with(&options).unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior().0.len(), 5);
+        assert!(collated.0[0].exterior().is_closed());
+        assert_eq!(collated.0[0].interiors().len(), 1);
+        assert!(collated.0[0].interiors()[0].is_closed());
+
+        let collated_into = uncollated.clone().collate_into_with(&options).unwrap();
+        assert_eq!(collated, collated_into);
+
+        let options = CollateOptions::new().open_rings(OpenRings::CloseWithin(0.5));
+        let (collated, issues) = uncollated.collate_lenient_with(&options);
+        assert_eq!(collated.0.len(), 0);
+        assert_eq!(
+            issues,
+            vec![
+                CollateIssue {
+                    ring: 0,
+                    error: CollateError::OpenRing {
+                        ring: 0,
+                        start: (0.0, 0.0),
+                        end: (3.0, 0.0),
+                    },
+                },
+                CollateIssue {
+                    ring: 1,
+                    error: CollateError::InsideRejectedRing {
+                        ring: 1,
+                        neighbour: 0
+                    },
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn open_triangle_int() {
+        let triangle: LineString<i64> = vec![(0, 0), (10, 20), (20, 0)].into();
+        let uncollated: MultiLineString<i64> = (vec![triangle]).into_iter().collect();
+
+        let options = CollateOptions::new()
+            .open_rings(OpenRings::CloseWithin(20.0))
+            .degenerate_rings(DegenerateRings::Reject);
+        let collated = uncollated.collate_with(&options).unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior().0.len(), 4);
+
+        let options = options.open_rings(OpenRings::CloseWithin(19.0));
+        assert!(matches!(
+            uncollated.collate_with(&options),
+            Err(CollateError::OpenRing { ring: 0, .. })
+        ));
+    }
+
+    fn segments<T: CoordNum>(points: &[(T, T)]) -> Vec<Line<T>> {
+        points
+            .iter()
+            .zip(points.iter().cycle().skip(1))
+            .map(|(start, end)| Line::new(*start, *end))
+            .collect()
+    }
+
+    #[test]
+    fn chained_segments() {
+        let mut lines = segments(&[(0.0, 0.0), (0.0, 4.0), (4.0, 4.0), (4.0, 0.0)]);
+        lines.extend(segments(&[(1.0, 1.0), (3.0, 1.0), (3.0, 3.0), (1.0, 3.0)]));
+        // Shuffle and flip some segments, as a mesh slicer would.
+        lines.swap(1, 6);
+        lines.swap(2, 4);
+        lines[3] = Line::new(lines[3].end, lines[3].start);
+        lines[5] = Line::new(lines[5].end, lines[5].start);
+
+        let chained = chain_segments(&lines, 0.0);
+        assert_eq!(chained.rings.0.len(), 2);
+        assert!(chained.rings.0.iter().all(|ring| ring.is_closed()));
+        assert!(chained.unchained.is_empty());
+
+        let collated = lines.collate().unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior().0.len(), 5);
+        assert_eq!(collated.0[0].interiors().len(), 1);
+        assert_eq!(lines.as_slice().collate().unwrap(), collated);
+    }
+
+    #[test]
+    fn chained_segments_int() {
+        let mut lines = segments(&[(0, 0), (0, 40), (40, 40), (40, 0)]);
+        lines.extend(segments(&[(10, 10), (30, 10), (30, 30), (10, 30)]));
+        lines.push(Line::new(Coord { x: 50, y: 50 }, Coord { x: 60, y: 50 }));
+        lines.reverse();
+
+        assert_eq!(
+            lines.collate().unwrap_err(),
+            CollateError::UnchainedSegments { segments: vec![0] }
+        );
+
+        let (collated, issues) = lines.collate_lenient();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].interiors().len(), 1);
+        assert_eq!(
+            issues,
+            vec![CollateIssue {
+                ring: 0,
+                error: CollateError::UnchainedSegments { segments: vec![0] },
+            }]
+        );
+    }
+
+    #[test]
+    fn chained_segments_within_tolerance() {
+        let lines = vec![
+            Line::new(Coord { x: 0.0, y: 0.0 }, Coord { x: 0.0, y: 4.0 }),
+            Line::new(Coord { x: 0.01, y: 4.0 }, Coord { x: 4.0, y: 4.0 }),
+            Line::new(Coord { x: 4.0, y: 0.0 }, Coord { x: 4.0, y: 3.99 }),
+            Line::new(Coord { x: 4.0, y: 0.0 }, Coord { x: 0.0, y: 0.01 }),
+        ];
+
+        assert!(matches!(
+            lines.collate(),
+            Err(CollateError::UnchainedSegments { .. })
+        ));
+
+        let options = CollateOptions::new().join_tolerance(0.05);
+        let chained = chain_segments(&lines, 0.05);
+        assert_eq!(chained.sources, vec![vec![0, 1, 2, 3]]);
+        assert!(chained.rings.0[0].is_closed());
+
+        let collated = lines.collate_with(&options).unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].interiors().len(), 0);
+    }
+
+    #[test]
+    fn chained_fragments() {
+        let fragments: MultiLineString<f64> = vec![
+            LineString::from(vec![(0.0, 0.0), (0.0, 4.0), (4.0, 4.0)]),
+            LineString::from(vec![
+                (1.0, 1.0),
+                (1.0, 3.0),
+                (3.0, 3.0),
+                (3.0, 1.0),
+                (1.0, 1.0),
+            ]),
+            LineString::from(vec![(4.0, 3.98), (4.0, 0.0), (0.02, 0.0)]),
+            LineString::from(vec![(10.0, 10.0), (12.0, 10.0)]),
+        ]
+        .into_iter()
+        .collect();
+
+        let chained = chain_fragments(&fragments, 0.01);
+        assert_eq!(chained.sources, vec![vec![1]]);
+        assert_eq!(chained.unchained, vec![0, 2, 3]);
+
+        let chained = chain_fragments(&fragments, 0.05);
+        assert_eq!(chained.sources, vec![vec![0, 2], vec![1]]);
+        assert_eq!(chained.unchained, vec![3]);
+        assert!(chained.rings.0.iter().all(|ring| ring.is_closed()));
+
+        let collated = chained.rings.collate().unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior().0.len(), 7);
+        assert_eq!(collated.0[0].interiors().len(), 1);
+    }
+
+    #[test]
+    fn chained_fragments_int() {
+        let fragments: MultiLineString<i64> = vec![
+            LineString::from(vec![(0, 0), (0, 40), (20, 40)]),
+            LineString::from(vec![(0, 0), (40, 0), (40, 40)]),
+            LineString::from(vec![(40, 41), (20, 41)]),
+        ]
+        .into_iter()
+        .collect();
+
+        let chained = chain_fragments(&fragments, 0.0);
+        assert!(chained.rings.0.is_empty());
+        assert_eq!(chained.unchained, vec![0, 1, 2]);
+
+        let chained = chain_fragments(&fragments, 1.0);
+        assert_eq!(chained.sources, vec![vec![0, 2, 1]]);
+        assert_eq!(
+            chained.rings.0[0],
+            LineString::from(vec![
+                (0, 0),
+                (0, 40),
+                (20, 40),
+                (20, 41),
+                (40, 41),
+                (40, 40),
+                (40, 0),
+                (0, 0)
+            ])
+        );
+        assert_eq!(chained.rings.collate().unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn chained_far_from_origin() {
+        // Far too many cells of 1e-9 for an i64 to count.
+        let (x, y) = (1e10, -1e10);
+        let fragments: MultiLineString<f64> = vec![
+            LineString::from(vec![(x, y), (x, y + 1.0), (x + 1.0, y + 1.0)]),
+            LineString::from(vec![(x + 1.0, y + 1.0), (x + 1.0, y), (x, y)]),
+        ]
+        .into_iter()
+        .collect();
+        let chained = chain_fragments(&fragments, 1e-9);
+        assert_eq!(chained.sources, vec![vec![0, 1]]);
+
+        let lines: Vec<Line<f64>> = fragments.iter().flat_map(|f| f.lines()).collect();
+        let options = CollateOptions::new().join_tolerance(1e-9);
+        assert_eq!(lines.collate_with(&options).unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn near_coincident_edges() {
+        let left: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)].into();
+        let right: LineString<f64> = vec![
+            (1.0 - 1e-12, 0.0),
+            (1.0 - 1e-12, 1.0),
+            (2.0, 1.0),
+            (2.0, 0.0),
+            (1.0 - 1e-12, 0.0),
+        ]
+        .into();
+        let uncollated: MultiLineString<f64> = (vec![right, left]).into_iter().collect();
+
+        assert!(matches!(
+            uncollated.collate(),
+            Err(CollateError::RingsCross { .. })
+        ));
+        assert!(matches!(
+            uncollated.collate_oriented(),
+            Err(CollateError::OutlineInOutline { .. })
+        ));
+
+        let options = CollateOptions::new().epsilon(1e-9);
+        let collated = uncollated.collate_with(&options).unwrap();
+        assert_eq!(collated.0.len(), 2);
+        assert!(collated.0.iter().all(|poly| poly.interiors().is_empty()));
+        assert_eq!(collated.0[0].exterior(), &uncollated.0[0]);
+
+        let options = options.orientation(OrientationPolicy::Enforce);
+        assert_eq!(uncollated.collate_with(&options).unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn tiny_epsilon_far_from_origin() {
+        // Far too many cells of 1e-9 for an i64 to count.
+        let square = |x: f64| -> LineString<f64> {
+            vec![
+                (x, x),
+                (x, x + 1.0),
+                (x + 1.0, x + 1.0),
+                (x + 1.0, x),
+                (x, x),
+            ]
+            .into()
+        };
+        let uncollated: MultiLineString<f64> =
+            (vec![square(1e10), square(-1e10)]).into_iter().collect();
+        let collated = uncollated
+            .collate_with(&CollateOptions::new().epsilon(1e-9))
+            .unwrap();
+        assert_eq!(collated.0.len(), 2);
+    }
+
+    #[test]
+    fn touching_and_snapped_int() {
+        let left: LineString<i64> = vec![(0, 0), (0, 10), (10, 10), (10, 0), (0, 0)].into();
+        let touching: LineString<i64> = vec![(10, 0), (10, 10), (20, 10), (20, 0), (10, 0)].into();
+        let uncollated: MultiLineString<i64> = (vec![left.clone(), touching]).into_iter().collect();
+        let collated = uncollated.collate().unwrap();
+        assert_eq!(collated.0.len(), 2);
+        assert!(collated.0.iter().all(|poly| poly.interiors().is_empty()));
+
+        let overlapping: LineString<i64> = vec![(9, 0), (9, 10), (20, 10), (20, 0), (9, 0)].into();
+        let uncollated: MultiLineString<i64> = (vec![left, overlapping]).into_iter().collect();
+        assert!(matches!(
+            uncollated.collate(),
+            Err(CollateError::RingsCross { .. })
+        ));
+
+        let collated = uncollated
+            .collate_with(&CollateOptions::new().epsilon(1.0))
+            .unwrap();
+        assert_eq!(collated.0.len(), 2);
+        assert_eq!(collated.0[1].exterior(), &uncollated.0[1]);
+    }
+
+    #[test]
+    fn exact_intersections_int() {
+        // At the sweep, both left edges cross between x = 1 and x = 2, which
+        // truncating division cannot tell apart.
+        let exterior: LineString<i64> = vec![(0, 0), (3, 10), (20, 10), (20, 0), (0, 0)].into();
+        let hole: LineString<i64> = vec![(1, 1), (10, 1), (10, 8), (3, 8), (1, 1)].into();
+        let uncollated: MultiLineString<i64> = (vec![hole, exterior]).into_iter().collect();
+
+        for uncollated in [uncollated.clone(), uncollated.translate(-55, -45)] {
+            let tree = uncollated.collation_tree().unwrap();
+            assert_eq!(tree.roots(), &[1]);
+            assert_eq!(tree.parent(0), Some(1));
+
+            let collated = uncollated.collate_oriented().unwrap();
+            assert_eq!(collated.0.len(), 1);
+            assert_eq!(collated.0[0].exterior(), &uncollated.0[1]);
+            assert_eq!(collated.0[0].interiors(), &uncollated.0[..1]);
+        }
+    }
+
+    #[test]
+    fn large_coordinates_int() {
+        let scale = 1_000_000_000_000;
+        let exterior: LineString<i64> = vec![(0, 0), (0, 3), (3, 3), (3, 0), (0, 0)].into();
+        let hole: LineString<i64> = vec![(1, 1), (2, 1), (2, 2), (1, 2), (1, 1)].into();
+        let scaled = |ls: &LineString<i64>, offset: i64| -> LineString<i64> {
+            ls.0.iter()
+                .map(|c| (c.x * scale / 3 - offset, c.y * scale / 3 - offset))
+                .collect::<Vec<_>>()
+                .into()
+        };
+
+        let uncollated: MultiLineString<i64> =
+            (vec![scaled(&hole, scale / 2), scaled(&exterior, scale / 2)])
+                .into_iter()
+                .collect();
+        let collated = uncollated.collate().unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior(), &uncollated.0[1]);
+        assert_eq!(collated.0[0].interiors(), &uncollated.0[..1]);
+
+        let too_large: MultiLineString<i64> =
+            (vec![scaled(&exterior, 0), scaled(&hole, -scale / 2)])
+                .into_iter()
+                .collect();
+        assert_eq!(
+            too_large.collate().unwrap_err(),
+            CollateError::OutOfRange { ring: 1, vertex: 1 }
+        );
+
+        let (collated, issues) = too_large.collate_lenient();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(
+            issues,
+            vec![CollateIssue {
+                ring: 1,
+                error: CollateError::OutOfRange { ring: 1, vertex: 1 },
+            }]
+        );
+    }
+
+    #[test]
+    fn sweep_through_vertices_int() {
+        // Every sweep position between the hole's bottom and top lands on
+        // the step in the exterior's left side.
+        let exterior: LineString<i64> =
+            vec![(0, 0), (0, 1), (1, 1), (1, 3), (10, 3), (10, 0), (0, 0)].into();
+        let hole: LineString<i64> = vec![(3, 1), (5, 1), (5, 2), (3, 2), (3, 1)].into();
+        let uncollated: MultiLineString<i64> = (vec![exterior, hole]).into_iter().collect();
+
+        let collated = uncollated.collate().unwrap();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].interiors(), &uncollated.0[1..]);
+        assert_eq!(uncollated.collate_oriented().unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn sweep_through_vertices() {
+        let exterior: LineString<f64> = vec![
+            (0.0, 0.0),
+            (0.0, 1.5),
+            (1.0, 1.5),
+            (1.0, 3.0),
+            (10.0, 3.0),
+            (10.0, 0.0),
+            (0.0, 0.0),
+        ]
+        .into();
+        let hole: LineString<f64> = vec![(3.0, 1.0), (5.0, 1.5), (3.0, 2.0), (3.0, 1.0)].into();
+        let island: LineString<f64> =
+            vec![(3.5, 1.5), (4.0, 1.25), (4.5, 1.5), (4.0, 1.75), (3.5, 1.5)].into();
+        let uncollated: MultiLineString<f64> = (vec![island, exterior, hole]).into_iter().collect();
+
+        let tree = uncollated.collation_tree().unwrap();
+        assert_eq!(tree.roots(), &[1]);
+        assert_eq!(tree.parent(2), Some(1));
+        assert_eq!(tree.parent(0), Some(2));
+        assert_eq!(uncollated.collate().unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn many_small_rings_int() {
+        // A frame with a hole full of small squares, each with a hole of its
+        // own, like the infill of a sliced layer.
+        let square = |x: i64, y: i64, size: i64| -> LineString<i64> {
+            vec![
+                (x, y),
+                (x, y + size),
+                (x + size, y + size),
+                (x + size, y),
+                (x, y),
+            ]
+            .into()
+        };
+        let mut rings = vec![square(0, 0, 1000), square(10, 10, 980)];
+        for i in 0..30 {
+            for j in 0..30 {
+                rings.push(square(20 + i * 32, 20 + j * 32, 30));
+                rings.push(square(30 + i * 32, 30 + j * 32, 10));
+            }
+        }
+        let uncollated: MultiLineString<i64> = rings.into_iter().collect();
+
+        let tree = uncollated.collation_tree().unwrap();
+        assert_eq!(tree.roots(), &[0]);
+        assert_eq!(tree.parent(1), Some(0));
+        for island in (2..uncollated.0.len()).step_by(2) {
+            assert_eq!(tree.parent(island), Some(1));
+            assert_eq!(tree.parent(island + 1), Some(island));
+        }
+        assert_eq!(uncollated.collate().unwrap().0.len(), 901);
+    }
+
+    #[test]
+    fn rings_enclosing_nothing() {
+        // A ring that runs out and back along a line is placed by its lowest
+        // vertex, and a spike off a ring is left out of the sweep.
+        let exterior: LineString<f64> = vec![
+            (0.0, 0.0),
+            (0.0, 10.0),
+            (10.0, 10.0),
+            (10.0, 0.0),
+            (5.0, 0.0),
+            (5.0, 3.0),
+            (5.0, 0.0),
+            (0.0, 0.0),
+        ]
+        .into();
+        let hole: LineString<f64> =
+            vec![(2.0, 4.0), (8.0, 4.0), (8.0, 8.0), (2.0, 8.0), (2.0, 4.0)].into();
+        let sliver: LineString<f64> = vec![(4.0, 5.0), (6.0, 7.0), (4.0, 5.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![exterior, hole, sliver]).into_iter().collect();
+
+        let tree = uncollated.collation_tree().unwrap();
+        assert_eq!(tree.roots(), &[0]);
+        assert_eq!(tree.parent(1), Some(0));
+        assert_eq!(tree.parent(2), Some(1));
+        assert_eq!(uncollated.collate().unwrap().0.len(), 2);
+    }
+
+    #[test]
+    fn every_ring_classified_int() {
+        // Rings whose y-ranges overlap, a small ring lying entirely between
+        // two of its neighbours' vertices, and a flat ring with no height.
+        let exterior: LineString<i64> =
+            vec![(0, 0), (0, 100), (50, 110), (100, 100), (100, 0), (0, 0)].into();
+        let hole: LineString<i64> =
+            vec![(10, 5), (10, 95), (40, 98), (90, 95), (90, 5), (10, 5)].into();
+        let island: LineString<i64> = vec![(20, 41), (20, 42), (21, 42), (21, 41), (20, 41)].into();
+        let flat: LineString<i64> = vec![(50, 60), (60, 60), (50, 60)].into();
+        let uncollated: MultiLineString<i64> =
+            (vec![flat, island, hole, exterior]).into_iter().collect();
+
+        let tree = uncollated.collation_tree().unwrap();
+        assert_eq!(tree.roots(), &[3]);
+        assert_eq!(tree.parent(2), Some(3));
+        assert_eq!(tree.parent(1), Some(2));
+        assert_eq!(tree.parent(0), Some(2));
+        assert_eq!(uncollated.collate().unwrap().0.len(), 3);
+    }
+
+    #[test]
+    fn every_ring_classified() {
+        let exterior: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)].into();
+        let flat: LineString<f64> = vec![(0.25, 0.5), (0.75, 0.5), (0.25, 0.5)].into();
+        let point: LineString<f64> = vec![(2.0, 0.5), (2.0, 0.5)].into();
+        let uncollated: MultiLineString<f64> = (vec![exterior, flat, point]).into_iter().collect();
+
+        let tree = uncollated.collation_tree().unwrap();
+        assert_eq!(tree.roots(), &[0, 2]);
+        assert_eq!(tree.parent(1), Some(0));
+    }
+
+    #[test]
+    fn validate_rings_int() {
+        let square: LineString<i64> = vec![(0, 0), (0, 10), (10, 10), (10, 0), (0, 0)].into();
+        let crossing: LineString<i64> = vec![(5, 5), (5, 15), (15, 15), (15, 5), (5, 5)].into();
+        let repeated: LineString<i64> =
+            vec![(20, 0), (20, 5), (20, 5), (25, 5), (25, 0), (20, 0)].into();
+        let open: LineString<i64> = vec![(30, 0), (30, 5), (35, 5), (35, 0)].into();
+        let sliver: LineString<i64> = vec![(40, 0), (40, 5), (40, 10), (40, 0)].into();
+        let short: LineString<i64> = vec![(50, 0), (50, 5), (50, 0)].into();
+        let uncollated: MultiLineString<i64> =
+            (vec![square, crossing, repeated, open, sliver, short])
+                .into_iter()
+                .collect();
+
+        let issues = validate_rings(&uncollated);
+        assert_eq!(
+            issues,
+            vec![
+                RingIssue::RingsCross {
+                    ring: 0,
+                    edge: 1,
+                    neighbour: 1,
+                    neighbour_edge: 0,
+                    at: (5, 10),
+                },
+                RingIssue::RingsCross {
+                    ring: 0,
+                    edge: 2,
+                    neighbour: 1,
+                    neighbour_edge: 3,
+                    at: (10, 5),
+                },
+                RingIssue::RepeatedVertex {
+                    ring: 2,
+                    vertex: 2,
+                    at: (20, 5),
+                },
+                RingIssue::Unclosed {
+                    ring: 3,
+                    start: (30, 0),
+                    end: (35, 0),
+                },
+                RingIssue::ZeroArea { ring: 4 },
+                RingIssue::TooFewPoints { ring: 5, points: 3 },
+            ]
+        );
+        assert_eq!(issues[0].rings(), vec![0, 1]);
+        assert_eq!(
+            issues[0].to_string(),
+            "Ring 0 crosses ring 1 at (5, 10), between edges 1 and 0"
+        );
+        assert!(validate_rings(&MultiLineString(vec![uncollated.0[0].clone()])).is_empty());
+    }
+
+    #[test]
+    fn validate_rings_self_intersection() {
+        let bowtie: LineString<f64> =
+            vec![(0.0, 0.0), (3.0, 3.0), (3.0, 0.0), (0.0, 6.0), (0.0, 0.0)].into();
+        let nan: LineString<f64> = vec![(0.0, 0.0), (f64::NAN, 1.0), (1.0, 1.0), (0.0, 0.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![bowtie, nan]).into_iter().collect();
+
+        assert_eq!(
+            validate_rings(&uncollated),
+            vec![
+                RingIssue::SelfIntersection {
+                    ring: 0,
+                    edge: 0,
+                    other_edge: 2,
+                    at: (2.0, 2.0),
+                },
+                RingIssue::NonFinite { ring: 1, vertex: 1 },
+            ]
+        );
+    }
+
+    #[test]
+    fn collate_self_intersection() {
+        let square: LineString<f64> = vec![
+            (10.0, 0.0),
+            (10.0, 1.0),
+            (11.0, 1.0),
+            (11.0, 0.0),
+            (10.0, 0.0),
+        ]
+        .into();
+        let bowtie: LineString<f64> =
+            vec![(0.0, 0.0), (3.0, 3.0), (3.0, 0.0), (0.0, 6.0), (0.0, 0.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![square, bowtie]).into_iter().collect();
+
+        let error = uncollated.collate().unwrap_err();
+        assert_eq!(
+            error,
+            CollateError::SelfIntersection {
+                ring: 1,
+                sweep: 0.0,
+                x: 3.0
+            }
+        );
+        assert_eq!(error.rings(), vec![1]);
+        assert_eq!(error.to_string(), "Ring 1 crosses itself at sweep 0.0 3.0");
+
+        let (collated, issues) = uncollated.collate_lenient();
+        assert_eq!(collated.0.len(), 1);
+        assert_eq!(collated.0[0].exterior(), &uncollated.0[0]);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].ring, 1);
+    }
+
+    #[test]
+    fn touching_rings_int() {
+        let square = |x: i64, y: i64, size: i64| -> LineString<i64> {
+            vec![
+                (x, y),
+                (x, y + size),
+                (x + size, y + size),
+                (x + size, y),
+                (x, y),
+            ]
+            .into()
+        };
+        let reversed =
+            |ls: LineString<i64>| -> LineString<i64> { ls.0.into_iter().rev().collect() };
+
+        // A hole touching its exterior at one vertex, another sharing part
+        // of its edge, an island in a hole touching it at a corner, and two
+        // exteriors sharing a vertex and an edge with the first.
+        let exterior = square(0, 0, 100);
+        let corner_hole = vec![(0, 0), (20, 10), (10, 20), (0, 0)].into();
+        let edge_hole = reversed(square(80, 40, 20));
+        let hole = reversed(square(30, 30, 40));
+        let island = square(30, 30, 10);
+        let corner_neighbour = square(100, 100, 50);
+        let edge_neighbour = square(100, 0, 50);
+        let uncollated: MultiLineString<i64> = (vec![
+            exterior,
+            corner_hole,
+            edge_hole,
+            hole,
+            island,
+            corner_neighbour,
+            edge_neighbour,
+        ])
+        .into_iter()
+        .collect();
+
+        for options in [
+            CollateOptions::new(),
+            CollateOptions::new().orientation(OrientationPolicy::Enforce),
+        ] {
+            let tree = uncollated.collation_tree_with(&options).unwrap();
+            assert_eq!(tree.roots(), &[0, 5, 6]);
+            assert_eq!(tree.parent(1), Some(0));
+            assert_eq!(tree.parent(2), Some(0));
+            assert_eq!(tree.parent(3), Some(0));
+            assert_eq!(tree.parent(4), Some(3));
+        }
+    }
+
+    #[test]
+    fn touching_rings() {
+        // Two exteriors sharing a vertex, each with a hole touching the
+        // shared vertex.
+        let left: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)].into();
+        let left_hole: LineString<f64> =
+            vec![(1.0, 1.0), (0.25, 0.5), (0.5, 0.25), (1.0, 1.0)].into();
+        let right: LineString<f64> =
+            vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0), (1.0, 1.0)].into();
+        let right_hole: LineString<f64> =
+            vec![(1.0, 1.0), (1.75, 1.5), (1.5, 1.75), (1.0, 1.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![right_hole, right, left_hole, left])
+            .into_iter()
+            .collect();
+
+        for options in [
+            CollateOptions::new(),
+            CollateOptions::new().orientation(OrientationPolicy::Enforce),
+        ] {
+            let tree = uncollated.collation_tree_with(&options).unwrap();
+            assert_eq!(tree.roots(), &[1, 3]);
+            assert_eq!(tree.parent(0), Some(1));
+            assert_eq!(tree.parent(2), Some(3));
+        }
+    }
+
+    #[test]
+    fn fill_rules_int() {
+        let square = |x: i64, y: i64| -> LineString<i64> {
+            vec![(x, y), (x + 2, y), (x + 2, y + 2), (x, y + 2), (x, y)].into()
+        };
+        let area = |rule: FillRule, lines: &MultiLineString<i64>| {
+            let options = CollateOptions::new().fill_rule(rule);
+            let collated = lines.collate_with(&options).unwrap();
+            assert_eq!(lines.clone().collate_into_with(&options).unwrap(), collated);
+            let ring = |ls: &LineString<i64>| {
+                ls.lines()
+                    .map(|l| l.start.x * l.end.y - l.end.x * l.start.y)
+                    .sum::<i64>()
+                    .abs()
+                    / 2
+            };
+            collated
+                .0
+                .iter()
+                .map(|p| ring(p.exterior()) - p.interiors().iter().map(ring).sum::<i64>())
+                .sum::<i64>()
+        };
+
+        // Both counter-clockwise, so the overlap winds twice.
+        let same: MultiLineString<i64> = (vec![square(0, 0), square(1, 1)]).into_iter().collect();
+        assert_eq!(area(FillRule::NonZero, &same), 7);
+        assert_eq!(area(FillRule::Positive, &same), 7);
+        assert_eq!(area(FillRule::Negative, &same), 0);
+        assert_eq!(area(FillRule::EvenOdd, &same), 6);
+
+        // One clockwise, so the overlap winds zero times.
+        let mut reversed = square(1, 1);
+        reversed.0.reverse();
+        let opposite: MultiLineString<i64> = (vec![square(0, 0), reversed]).into_iter().collect();
+        assert_eq!(area(FillRule::NonZero, &opposite), 6);
+        assert_eq!(area(FillRule::Positive, &opposite), 3);
+        assert_eq!(area(FillRule::Negative, &opposite), 3);
+        assert_eq!(area(FillRule::EvenOdd, &opposite), 6);
+
+        let union = same
+            .collate_with(&CollateOptions::new().fill_rule(FillRule::NonZero))
+            .unwrap();
+        assert_eq!(union.0.len(), 1);
+        assert_eq!(union.0[0].exterior().0.len(), 9);
+        assert!(union.0[0].interiors().is_empty());
+    }
+
+    #[test]
+    fn fill_rules() {
+        // A pentagram crosses itself, winding twice around its middle.
+        let mut pentagram: LineString<f64> = (0..5)
+            .map(|i| {
+                let angle =
+                    std::f64::consts::FRAC_PI_2 + (i * 2) as f64 * std::f64::consts::TAU / 5.0;
+                (angle.cos() * 10.0, angle.sin() * 10.0)
+            })
+            .collect::<Vec<_>>()
+            .into();
+        pentagram.close();
+        let hole: LineString<f64> = vec![
+            (-1.0, -1.0),
+            (-1.0, 1.0),
+            (1.0, 1.0),
+            (1.0, -1.0),
+            (-1.0, -1.0),
+        ]
+        .into();
+        let lines: MultiLineString<f64> = (vec![pentagram, hole]).into_iter().collect();
+
+        let non_zero = lines
+            .collate_with(&CollateOptions::new().fill_rule(FillRule::NonZero))
+            .unwrap();
+        assert_eq!(non_zero.0.len(), 1);
+        assert_eq!(non_zero.0[0].exterior().0.len(), 11);
+        assert!(non_zero.0[0].interiors().is_empty());
+
+        // Under even-odd the middle is empty, bar the square, which is
+        // inside it, leaving the five points.
+        let even_odd = lines
+            .collate_with(&CollateOptions::new().fill_rule(FillRule::EvenOdd))
+            .unwrap();
+        assert_eq!(even_odd.0.len(), 6);
+        assert!(even_odd.unsigned_area() < non_zero.unsigned_area());
+
+        let filled = fill_rings(&lines, FillRule::EvenOdd).unwrap();
+        assert!(validate_rings(&filled).is_empty());
+    }
+
+    #[test]
+    fn collate_by_depth_int() {
+        let square = |at: i64, size: i64| -> LineString<i64> {
+            vec![
+                (at, at),
+                (at, at + size),
+                (at + size, at + size),
+                (at + size, at),
+                (at, at),
+            ]
+            .into()
+        };
+        // A shell with a hole, an island in the hole with a hole of its own,
+        // a second island inside that, and a separate shell.
+        let uncollated: MultiLineString<i64> = (vec![
+            square(4, 12),
+            square(0, 20),
+            square(6, 8),
+            square(2, 16),
+            square(8, 4),
+            square(30, 5),
+        ])
+        .into_iter()
+        .collect();
+
+        let depths = uncollated.collate_by_depth().unwrap();
+        assert_eq!(depths.len(), 3);
+        assert_eq!(depths[0].0.len(), 2);
+        assert_eq!(depths[0].0[0].exterior(), &uncollated.0[1]);
+        assert_eq!(depths[0].0[0].interiors(), &uncollated.0[3..4]);
+        assert_eq!(depths[0].0[1].exterior(), &uncollated.0[5]);
+        assert_eq!(depths[1].0.len(), 1);
+        assert_eq!(depths[1].0[0].exterior(), &uncollated.0[0]);
+        assert_eq!(depths[1].0[0].interiors(), &uncollated.0[2..3]);
+        assert_eq!(depths[2].0.len(), 1);
+        assert_eq!(depths[2].0[0].exterior(), &uncollated.0[4]);
+
+        let flat: Vec<Polygon<i64>> = depths.into_iter().flat_map(|mp| mp.0).collect();
+        assert_eq!(flat.len(), uncollated.collate().unwrap().0.len());
+    }
+
+    #[test]
+    fn collate_by_depth() {
+        let exterior: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 3.0), (3.0, 3.0), (3.0, 0.0), (0.0, 0.0)].into();
+        let hole: LineString<f64> =
+            vec![(1.0, 1.0), (1.0, 2.0), (2.0, 2.0), (2.0, 1.0), (1.0, 1.0)].into();
+        let island: LineString<f64> = vec![
+            (1.25, 1.25),
+            (1.25, 1.75),
+            (1.75, 1.75),
+            (1.75, 1.25),
+            (1.25, 1.25),
+        ]
+        .into();
+        let uncollated: MultiLineString<f64> = (vec![island, hole, exterior]).into_iter().collect();
+
+        let depths = uncollated
+            .collate_by_depth_with(&CollateOptions::new().winding(Winding::CounterClockwise))
+            .unwrap();
+        assert_eq!(depths.len(), 2);
+        assert_eq!(depths[0].0.len(), 1);
+        assert_eq!(depths[0].0[0].interiors().len(), 1);
+        assert_eq!(depths[1].0.len(), 1);
+        assert!(depths[1].0[0].exterior().is_ccw());
+
+        let tree = uncollated.collation_tree().unwrap();
+        let by_depth = tree.polygons_by_depth();
+        assert_eq!(by_depth[0][0].exterior, 2);
+        assert_eq!(by_depth[1][0].exterior, 0);
+        assert!(MultiLineString::<f64>(vec![])
+            .collate_by_depth()
+            .unwrap()
+            .is_empty());
+    }
+
+    /// An exterior with a hole, and an island in the hole, scaled by `scale`
+    /// and moved by `offset`.
+    fn nested_squares<T>(scale: T, offset: T) -> MultiLineString<T>
+    where
+        T: CoordNum,
+    {
+        let square = |at: i64, size: i64| -> LineString<T> {
+            [
+                (at, at),
+                (at, at + size),
+                (at + size, at + size),
+                (at + size, at),
+                (at, at),
+            ]
+            .iter()
+            .map(|(x, y)| Coord {
+                x: T::from(*x).unwrap() * scale + offset,
+                y: T::from(*y).unwrap() * scale + offset,
+            })
+            .collect()
+        };
+        MultiLineString(vec![square(2, 2), square(0, 6), square(1, 4)])
+    }
+
+    fn check_nested_squares<T>(uncollated: MultiLineString<T>)
+    where
+        T: CoordNum + Numeric + std::fmt::Debug,
+    {
+        let collated = uncollated.collate().unwrap();
+        assert_eq!(collated.0.len(), 2);
+        assert_eq!(collated.0[0].exterior(), &uncollated.0[0]);
+        assert_eq!(collated.0[1].exterior(), &uncollated.0[1]);
+        assert_eq!(collated.0[1].interiors(), &uncollated.0[2..]);
+        assert_eq!(validate_rings(&uncollated), vec![]);
+        assert_eq!(
+            uncollated
+                .collate_with(&CollateOptions::new().fill_rule(FillRule::EvenOdd))
+                .unwrap()
+                .0
+                .len(),
+            2
+        );
+    }
+
+    #[test]
+    fn coordinate_types_int() {
+        check_nested_squares(nested_squares(1i8, 100));
+        check_nested_squares(nested_squares(1000i16, -3000));
+        check_nested_squares(nested_squares(1u8, 0));
+        check_nested_squares(nested_squares(10000u16, 0));
+        check_nested_squares(nested_squares(1 << 28, -(1 << 30)));
+        // Near the top of the range, where coordinate differences would
+        // underflow if taken in the coordinate type.
+        check_nested_squares(nested_squares(1u32 << 28, u32::MAX - (6 << 28)));
+        check_nested_squares(nested_squares(1u64 << 30, 1 << 39));
+        check_nested_squares(nested_squares(1i128, -(1 << 40)));
+        check_nested_squares(nested_squares(7u128, 0));
+        check_nested_squares(nested_squares(3usize, 5));
+        check_nested_squares(nested_squares(-3isize, 5));
+
+        let too_large = nested_squares(1u64, 1 << 41);
+        assert_eq!(
+            too_large.collate().unwrap_err(),
+            CollateError::OutOfRange { ring: 0, vertex: 0 }
+        );
+    }
+
+    #[test]
+    fn coordinate_types() {
+        check_nested_squares(nested_squares(0.5f32, -1.0e6));
+        check_nested_squares(nested_squares(1.0e-3f64, 0.0));
+    }
+
+    /// A unit-typed coordinate, to check that types outside the crate can be
+    /// collated.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Micrometers(i64);
+
+    macro_rules! micrometers_op {
+        ($($op:ident, $method:ident;)*) => {
+            $(
+                impl std::ops::$op for Micrometers {
+                    type Output = Micrometers;
+
+                    fn $method(self, other: Micrometers) -> Micrometers {
+                        Micrometers(std::ops::$op::$method(self.0, other.0))
+                    }
+                }
+            )*
+        };
+    }
+
+    micrometers_op!(Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem;);
+
+    impl num_traits::Zero for Micrometers {
+        fn zero() -> Self {
+            Micrometers(0)
+        }
+
+        fn is_zero(&self) -> bool {
+            self.0 == 0
+        }
+    }
+
+    impl num_traits::One for Micrometers {
+        fn one() -> Self {
+            Micrometers(1)
+        }
+    }
+
+    impl num_traits::Num for Micrometers {
+        type FromStrRadixErr = std::num::ParseIntError;
+
+        fn from_str_radix(s: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+            i64::from_str_radix(s, radix).map(Micrometers)
+        }
+    }
+
+    impl num_traits::ToPrimitive for Micrometers {
+        fn to_i64(&self) -> Option<i64> {
+            Some(self.0)
+        }
+
+        fn to_u64(&self) -> Option<u64> {
+            self.0.to_u64()
+        }
+    }
+
+    impl num_traits::NumCast for Micrometers {
+        fn from<N: num_traits::ToPrimitive>(n: N) -> Option<Self> {
+            n.to_i64().map(Micrometers)
+        }
+    }
+
+    impl Numeric for Micrometers {
+        type Wide = i128;
+
+        fn to_float(self) -> f64 {
+            self.0.to_float()
+        }
+
+        fn from_float(x: f64) -> Self {
+            Micrometers(i64::from_float(x))
+        }
+
+        fn in_range(self) -> bool {
+            self.0.in_range()
+        }
+
+        fn widen(self) -> i128 {
+            self.0.widen()
+        }
+
+        fn from_wide(wide: i128) -> Self {
+            Micrometers(i64::from_wide(wide))
+        }
+
+        fn wide_to_float(wide: i128) -> f64 {
+            i64::wide_to_float(wide)
+        }
+    }
+
+    #[test]
+    fn coordinate_newtype() {
+        check_nested_squares(nested_squares(Micrometers(1000), Micrometers(-2500)));
+
+        let too_large = nested_squares(Micrometers(1), Micrometers(1 << 41));
+        assert_eq!(
+            too_large.collate().unwrap_err().to_string(),
+            "Ring 0 has a coordinate out of range at vertex 0"
+        );
+    }
+
+    #[test]
+    fn quantized_collation() {
+        let exterior: LineString<f64> = vec![
+            (0.0, 0.0),
+            (0.0, 3.000_000_1),
+            (3.0, 3.0),
+            (3.0, 0.0),
+            (0.0, 0.0),
+        ]
+        .into();
+        let hole: LineString<f64> =
+            vec![(1.0, 1.0), (2.0, 1.0), (2.0, 2.0), (1.0, 2.0), (1.0, 1.0)].into();
+        // The same hole, a hair off and wound the other way.
+        let twin: LineString<f64> = vec![
+            (1.0, 1.0),
+            (1.0, 2.0),
+            (2.0, 2.000_000_2),
+            (2.0, 1.0),
+            (1.0, 1.0),
+        ]
+        .into();
+        let speck: LineString<f64> = vec![
+            (0.5, 0.5),
+            (0.5, 0.500_000_1),
+            (0.500_000_1, 0.5),
+            (0.5, 0.5),
+        ]
+        .into();
+        let lines: MultiLineString<f64> = (vec![exterior, hole, speck, twin]).into_iter().collect();
+
+        let quantized = collate_quantized(
+            &lines,
+            1e3,
+            &CollateOptions::new().winding(Winding::CounterClockwise),
+        )
+        .unwrap();
+        assert_eq!(quantized.collapsed, vec![2]);
+        assert_eq!(quantized.merged, vec![(3, 1)]);
+        assert_eq!(quantized.polygons.0.len(), 1);
+        // The output rings are the input rings, not rounded to the grid.
+        let polygon = &quantized.polygons.0[0];
+        assert!(polygon.exterior().0.contains(&Coord {
+            x: 0.0,
+            y: 3.000_000_1
+        }));
+        assert!(polygon.exterior().is_ccw());
+        let mut hole = lines.0[1].clone();
+        hole.0.reverse();
+        assert_eq!(polygon.interiors(), &[hole]);
+    }
+
+    #[test]
+    fn quantized_tolerances_in_input_units() {
+        let open: LineString<f64> = vec![
+            (0.0, 0.0),
+            (0.0, 10.0),
+            (10.0, 10.0),
+            (10.0, 0.0),
+            (3.0, 0.0),
+        ]
+        .into();
+        let lines: MultiLineString<f64> = (vec![open]).into_iter().collect();
+        let options = CollateOptions::new().open_rings(OpenRings::CloseWithin(3.5));
+        assert_eq!(lines.collate_with(&options).unwrap().0.len(), 1);
+        let quantized = collate_quantized(&lines, 1e6, &options).unwrap();
+        assert_eq!(quantized.polygons.0.len(), 1);
+
+        let left: LineString<f64> =
+            vec![(0.0, 0.0), (0.0, 1.0), (1.0, 1.0), (1.0, 0.0), (0.0, 0.0)].into();
+        let right: LineString<f64> = vec![
+            (0.999, 0.0),
+            (0.999, 1.0),
+            (2.0, 1.0),
+            (2.0, 0.0),
+            (0.999, 0.0),
+        ]
+        .into();
+        let lines: MultiLineString<f64> = (vec![left, right]).into_iter().collect();
+        assert!(matches!(
+            collate_quantized(&lines, 1e3, &CollateOptions::new()),
+            Err(CollateError::RingsCross { .. })
+        ));
+        let options = CollateOptions::new().epsilon(2e-3);
+        let quantized = collate_quantized(&lines, 1e3, &options).unwrap();
+        assert_eq!(quantized.polygons.0.len(), 2);
+    }
+
+    #[test]
+    fn quantized_collation_errors() {
+        let one: LineString<f32> =
+            vec![(0.0, 0.0), (0.0, 2.0), (2.0, 2.0), (2.0, 0.0), (0.0, 0.0)].into();
+        let two: LineString<f32> =
+            vec![(1.0, 0.5), (1.0, 1.5), (3.0, 1.5), (3.0, 0.5), (1.0, 0.5)].into();
+        let lines: MultiLineString<f32> = (vec![one.clone(), two]).into_iter().collect();
+
+        assert_eq!(
+            collate_quantized(&lines, 100.0, &CollateOptions::new()).unwrap_err(),
+            CollateError::RingsCross {
+                ring: 0,
+                neighbour: 1,
+                sweep: 0.5,
+                x: 2.0,
+            }
+        );
+
+        let far: LineString<f32> =
+            vec![(1e7, 0.0), (1e7, 1.0), (1e7 + 1.0, 0.0), (1e7, 0.0)].into();
+        let lines: MultiLineString<f32> = (vec![one, far]).into_iter().collect();
+        assert_eq!(
+            collate_quantized(&lines, 1e6, &CollateOptions::new()).unwrap_err(),
+            CollateError::OutOfRange { ring: 1, vertex: 0 }
+        );
+
+        for scale in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                collate_quantized(&lines, scale, &CollateOptions::new()),
+                Err(CollateError::InvalidScale { .. })
+            ));
+        }
+        assert_eq!(
+            collate_quantized(
+                &lines,
+                1.0,
+                &CollateOptions::new().fill_rule(FillRule::NonZero)
+            )
+            .unwrap_err(),
+            CollateError::FillRuleNotSupported
+        );
+    }
+
+    #[test]
+    fn chained_fragments_past_a_spur() {
+        let fragments: MultiLineString<f64> = vec![
+            LineString::from(vec![(0.0, 0.0), (0.0, 4.0), (4.0, 4.0)]),
+            LineString::from(vec![(4.0, 4.02), (4.0, 0.0), (0.0, 0.01)]),
+            LineString::from(vec![(4.005, 4.0), (10.0, 10.0)]),
+        ]
+        .into_iter()
+        .collect();
+
+        let chained = chain_fragments(&fragments, 0.05);
+        assert_eq!(chained.sources, vec![vec![0, 1]]);
+        assert_eq!(chained.unchained, vec![2]);
+        assert_eq!(chained.rings.collate().unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn chained_fragments_past_a_spur_int() {
+        let fragments: MultiLineString<i64> = vec![
+            LineString::from(vec![(0, 0), (0, 40), (40, 40)]),
+            LineString::from(vec![(41, 40), (100, 100)]),
+            LineString::from(vec![(40, 42), (40, 0), (0, 1)]),
+        ]
+        .into_iter()
+        .collect();
+
+        let chained = chain_fragments(&fragments, 3.0);
+        assert_eq!(chained.sources, vec![vec![0, 2]]);
+        assert_eq!(chained.unchained, vec![1]);
+        assert_eq!(chained.rings.collate().unwrap().0.len(), 1);
+    }
+
+    #[test]
+    fn near_duplicate_crossing_edges() {
+        // The left square's right side bends through the right square's left
+        // side, never more than 1e-10 from it.
+        let left: LineString<f64> = vec![
+            (0.0, 0.0),
+            (0.0, 1.0),
+            (1.0, 1.0),
+            (1.0 + 1e-10, 0.8),
+            (1.0 - 1e-10, 0.2),
+            (1.0, 0.0),
+            (0.0, 0.0),
+        ]
+        .into();
+        let right: LineString<f64> =
+            vec![(1.0, 0.0), (1.0, 1.0), (2.0, 1.0), (2.0, 0.0), (1.0, 0.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![left, right]).into_iter().collect();
+
+        assert!(matches!(
+            uncollated.collate(),
+            Err(CollateError::RingsCross { .. })
+        ));
+        let collated = uncollated
+            .collate_with(&CollateOptions::new().epsilon(1e-6))
+            .unwrap();
+        assert_eq!(collated.0.len(), 2);
+        assert!(collated.0.iter().all(|poly| poly.interiors().is_empty()));
+    }
+
+    #[test]
+    fn near_duplicate_crossing_edges_int() {
+        let left: LineString<i64> = vec![
+            (0, 0),
+            (0, 100),
+            (100, 100),
+            (101, 80),
+            (99, 20),
+            (100, 0),
+            (0, 0),
+        ]
+        .into();
+        let right: LineString<i64> =
+            vec![(100, 0), (100, 100), (200, 100), (200, 0), (100, 0)].into();
+        let uncollated: MultiLineString<i64> = (vec![left, right]).into_iter().collect();
+
+        assert!(matches!(
+            uncollated.collate(),
+            Err(CollateError::RingsCross { .. })
+        ));
+        let collated = uncollated
+            .collate_with(&CollateOptions::new().epsilon(2.0))
+            .unwrap();
+        assert_eq!(collated.0.len(), 2);
+    }
+
+    #[test]
+    fn validate_rings_touching_int() {
+        let square: LineString<i64> = vec![(0, 0), (2, 0), (2, 2), (0, 2), (0, 0)].into();
+        let overlapping: LineString<i64> = vec![(1, 0), (3, 0), (3, 2), (1, 2), (1, 0)].into();
+        let uncollated: MultiLineString<i64> = (vec![square, overlapping]).into_iter().collect();
+
+        assert!(matches!(
+            uncollated.collate(),
+            Err(CollateError::RingsCross { .. })
+        ));
+        let issues = validate_rings(&uncollated);
+        assert_eq!(
+            issues,
+            vec![
+                RingIssue::EdgesOverlap {
+                    ring: 0,
+                    edge: 0,
+                    neighbour: 1,
+                    neighbour_edge: 0,
+                    from: (1, 0),
+                    to: (2, 0),
+                },
+                RingIssue::EdgesOverlap {
+                    ring: 0,
+                    edge: 2,
+                    neighbour: 1,
+                    neighbour_edge: 2,
+                    from: (2, 2),
+                    to: (1, 2),
+                },
+            ]
+        );
+        assert_eq!(
+            issues[0].to_string(),
+            "Edge 0 of ring 0 runs along edge 0 of ring 1 from (1, 0) to (2, 0)"
+        );
+
+        // A figure of eight, pinched where vertices 1 and 4 meet, and a ring
+        // with a vertex on the far side of its own edge.
+        let eight: LineString<i64> =
+            vec![(0, 0), (1, 1), (2, 0), (2, 2), (1, 1), (0, 2), (0, 0)].into();
+        let dented: LineString<i64> =
+            vec![(10, 0), (14, 0), (14, 4), (12, 0), (10, 4), (10, 0)].into();
+        let hole: LineString<i64> = vec![(20, 0), (20, 4), (24, 4), (24, 0), (20, 0)].into();
+        let touching: LineString<i64> = vec![(20, 2), (21, 1), (21, 3), (20, 2)].into();
+        let uncollated: MultiLineString<i64> =
+            (vec![eight, dented, hole, touching]).into_iter().collect();
+        assert_eq!(
+            validate_rings(&uncollated),
+            vec![
+                RingIssue::VertexTouches {
+                    ring: 0,
+                    vertex: 4,
+                    neighbour: 0,
+                    neighbour_edge: 1,
+                    at: (1, 1),
+                },
+                RingIssue::VertexTouches {
+                    ring: 1,
+                    vertex: 3,
+                    neighbour: 1,
+                    neighbour_edge: 0,
+                    at: (12, 0),
+                },
+                RingIssue::VertexTouches {
+                    ring: 3,
+                    vertex: 0,
+                    neighbour: 2,
+                    neighbour_edge: 0,
+                    at: (20, 2),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_rings_rounds_crossings_int() {
+        let sloped: LineString<i64> = vec![(0, 0), (9, 5), (9, 0), (0, 0)].into();
+        let upright: LineString<i64> = vec![(3, -1), (3, 4), (4, 4), (4, -1), (3, -1)].into();
+        let uncollated: MultiLineString<i64> = (vec![sloped, upright]).into_iter().collect();
+
+        // (3, 1.67) rounds to (3, 2).
+        assert_eq!(
+            validate_rings(&uncollated)[0],
+            RingIssue::RingsCross {
+                ring: 0,
+                edge: 0,
+                neighbour: 1,
+                neighbour_edge: 0,
+                at: (3, 2),
+            }
+        );
+    }
+
+    #[test]
+    fn validate_rings_touching() {
+        let square: LineString<f64> =
+            vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0), (0.0, 0.0)].into();
+        let inside: LineString<f64> = vec![(0.5, 0.0), (1.5, 0.0), (1.0, 1.0), (0.5, 0.0)].into();
+        let uncollated: MultiLineString<f64> = (vec![square, inside]).into_iter().collect();
+        assert_eq!(
+            validate_rings(&uncollated),
+            vec![RingIssue::EdgesOverlap {
+                ring: 0,
+                edge: 0,
+                neighbour: 1,
+                neighbour_edge: 0,
+                from: (0.5, 0.0),
+                to: (1.5, 0.0),
+            }]
+        );
+    }
+
+    #[test]
+    fn filled_segments() {
+        let ring: LineString<f64> = vec![
+            (7.0, 2.0),
+            (0.0, 4.0),
+            (3.0, 1.0),
+            (2.0, 6.0),
+            (3.0, 1.0),
+            (7.0, 2.0),
+        ]
+        .into();
+        let segments: Vec<Line<f64>> = ring.lines().collect();
+        let options = CollateOptions::new().fill_rule(FillRule::EvenOdd);
+
+        let (collated, issues) = segments.collate_lenient_with(&options);
+        assert!(issues.is_empty());
+        assert_eq!(segments.collate_with(&options).unwrap(), collated);
+        assert_eq!(
+            segments.collate_by_depth_with(&options).unwrap()[0],
+            collated
+        );
+    }
+
+    #[test]
+    fn filled_crossings_collate() {
+        let one: LineString<f64> = vec![
+            (4.0, 17.0),
+            (6.0, 12.0),
+            (5.0, 2.0),
+            (19.0, 3.0),
+            (4.0, 5.0),
+            (4.0, 17.0),
+        ]
+        .into();
+        let two: LineString<f64> = vec![
+            (7.0, 17.0),
+            (11.0, 0.0),
+            (6.0, 15.0),
+            (10.0, 3.0),
+            (12.0, 8.0),
+            (7.0, 17.0),
+        ]
+        .into();
+        let lines: MultiLineString<f64> = (vec![one, two]).into_iter().collect();
+        let options = CollateOptions::new().fill_rule(FillRule::NonZero);
+        assert!(lines.collate_with(&options).is_ok());
+    }
+
+    #[test]
+    fn filled_ring_above_peak_collates() {
+        // The triangle's sides end at its peak, below the square, where no
+        // other piece starts.
+        let triangle: LineString<f64> = vec![(0., 0.), (10., 0.), (5., 5.), (0., 0.)].into();
+        let square: LineString<f64> = vec![(4., 6.), (6., 6.), (6., 8.), (4., 8.), (4., 6.)].into();
+        let lines: MultiLineString<f64> = (vec![triangle, square]).into_iter().collect();
+        for rule in [FillRule::NonZero, FillRule::EvenOdd] {
+            let options = CollateOptions::new().fill_rule(rule);
+            let collated = lines.collate_with(&options).unwrap();
+            assert_eq!(collated.0.len(), 2, "{:?}", rule);
+            assert_eq!(collated.unsigned_area(), 29.0, "{:?}", rule);
+        }
+    }
+
+    /// The next number below `n` from a linear congruential generator.
+    fn next_random(seed: &mut u64, n: u64) -> i64 {
+        *seed = seed
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        ((*seed >> 33) % n) as i64
+    }
+
+    /// Random rings of up to ten vertices on a 1000 by 1000 grid, at
+    /// multiples of `step`, from a fixed seed.
+    fn random_rings(seed: &mut u64, rings: usize, step: i64) -> Vec<Vec<(i64, i64)>> {
+        let cells = (1000 / step) as u64;
+        (0..rings)
+            .map(|_| {
+                let vertices = 3 + next_random(seed, 8) as usize;
+                let mut ring: Vec<(i64, i64)> = (0..vertices)
+                    .map(|_| {
+                        (
+                            next_random(seed, cells) * step,
+                            next_random(seed, cells) * step,
+                        )
+                    })
+                    .collect();
+                ring.push(ring[0]);
+                ring
+            })
+            .collect()
+    }
+
+    /// The winding number of closed `rings` around `p`, counting the edges
+    /// crossing the ray right of it: one for each running up, less one for
+    /// each running down.
+    fn winding_number(rings: &[Vec<(f64, f64)>], p: (f64, f64)) -> i32 {
+        rings
+            .iter()
+            .flat_map(|ring| ring.windows(2))
+            .map(|edge| {
+                let ((ax, ay), (bx, by)) = (edge[0], edge[1]);
+                let left = (bx - ax) * (p.1 - ay) - (by - ay) * (p.0 - ax);
+                if ay <= p.1 && p.1 < by && left > 0.0 {
+                    1
+                } else if by <= p.1 && p.1 < ay && left < 0.0 {
+                    -1
+                } else {
+                    0
+                }
+            })
+            .sum()
+    }
+
+    /// How far `p` is from the nearest edge of `rings`.
+    fn edge_distance(rings: &[Vec<(f64, f64)>], p: (f64, f64)) -> f64 {
+        rings
+            .iter()
+            .flat_map(|ring| ring.windows(2))
+            .map(|edge| {
+                let ((ax, ay), (bx, by)) = (edge[0], edge[1]);
+                let (dx, dy) = (bx - ax, by - ay);
+                let length = dx * dx + dy * dy;
+                let t = if length == 0.0 {
+                    0.0
+                } else {
+                    (((p.0 - ax) * dx + (p.1 - ay) * dy) / length).clamp(0.0, 1.0)
+                };
+                (p.0 - ax - t * dx).hypot(p.1 - ay - t * dy)
+            })
+            .fold(f64::INFINITY, f64::min)
+    }
+
+    /// Every ring of `polygons`, with its coordinates mapped by `f`.
+    fn polygon_rings<T: CoordNum>(
+        polygons: &MultiPolygon<T>,
+        f: impl Fn(Coord<T>) -> (f64, f64),
+    ) -> Vec<Vec<(f64, f64)>> {
+        polygons
+            .0
+            .iter()
+            .flat_map(|polygon| std::iter::once(polygon.exterior()).chain(polygon.interiors()))
+            .map(|ring| ring.0.iter().map(|c| f(*c)).collect())
+            .collect()
+    }
+
+    #[test]
+    fn filled_random_rings_collate() {
+        let rules = [
+            FillRule::EvenOdd,
+            FillRule::NonZero,
+            FillRule::Positive,
+            FillRule::Negative,
+        ];
+        let mut seed = 7;
+        for case in 0..200 {
+            // Every other case on a coarse grid, where rings often share
+            // vertices and edges, and edges are often horizontal.
+            let step = if case % 2 == 0 { 1 } else { 50 };
+            let rings = random_rings(&mut seed, 1 + case % 4, step);
+            let int: MultiLineString<i64> = rings
+                .iter()
+                .map(|ring| LineString::from(ring.clone()))
+                .collect();
+            let float: MultiLineString<f64> = rings
+                .iter()
+                .map(|ring| {
+                    ring.iter()
+                        .map(|(x, y)| (*x as f64 / 7.0 + 1e4, *y as f64 / 7.0))
+                        .collect::<LineString<f64>>()
+                })
+                .collect();
+
+            // Points clear of the edges, where rounding crossings to the
+            // grid can't move the boundary past them.
+            let grid: Vec<Vec<(f64, f64)>> = rings
+                .iter()
+                .map(|ring| ring.iter().map(|(x, y)| (*x as f64, *y as f64)).collect())
+                .collect();
+            let samples: Vec<(f64, f64)> = (0..100)
+                .map(|_| {
+                    (
+                        next_random(&mut seed, 1000) as f64 + 0.5,
+                        next_random(&mut seed, 1000) as f64 + 0.5,
+                    )
+                })
+                .filter(|p| edge_distance(&grid, *p) > 2.0)
+                .collect();
+
+            for rule in rules {
+                let options = CollateOptions::new().fill_rule(rule);
+                let collated = int
+                    .collate_with(&options)
+                    .unwrap_or_else(|error| panic!("{} {:?} {:?}", error, rule, int));
+                let int_rings = polygon_rings(&collated, |c| (c.x as f64, c.y as f64));
+
+                let collated = float
+                    .collate_with(&options)
+                    .unwrap_or_else(|error| panic!("{} {:?} {:?}", error, rule, float));
+                let float_rings = polygon_rings(&collated, |c| ((c.x - 1e4) * 7.0, c.y * 7.0));
+
+                // Collated polygons don't overlap, so a point is in one if
+                // their rings wind an odd number of times around it.
+                for p in &samples {
+                    let fills = rule.fills(winding_number(&grid, *p));
+                    assert_eq!(
+                        winding_number(&int_rings, *p) % 2 != 0,
+                        fills,
+                        "{:?} {:?} at {:?}",
+                        rule,
+                        int,
+                        p
+                    );
+                    assert_eq!(
+                        winding_number(&float_rings, *p) % 2 != 0,
+                        fills,
+                        "{:?} {:?} at {:?}",
+                        rule,
+                        float,
+                        p
+                    );
+                }
+            }
+        }
+    }
 }
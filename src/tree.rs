@@ -0,0 +1,148 @@
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+/// The input rings making up one collated polygon.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PolygonIndices {
+    /// Input index of the polygon's exterior ring.
+    pub exterior: usize,
+    /// Input indices of the polygon's holes, in input order.
+    pub interiors: Vec<usize>,
+}
+
+/// The full containment hierarchy of a set of collated rings.
+///
+/// Rings are identified by their index in the input. Every ring that took
+/// part in the collation has a nesting depth: depth 0 rings are top-level
+/// exteriors, depth 1 rings are their holes, depth 2 rings are islands sitting
+/// inside those holes, and so on. Even depths are exteriors and odd depths are
+/// holes. Rings left out of the collation (for example skipped degenerate
+/// rings) have no depth, no parent and no children.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CollationTree {
+    parents: Vec<Option<usize>>,
+    children: Vec<Vec<usize>>,
+    depths: Vec<Option<usize>>,
+    roots: Vec<usize>,
+    order: Vec<usize>,
+}
+
+impl CollationTree {
+    /// Build the tree from the parent of every ring. Rings in `excluded` are
+    /// left out, as are rings whose parent chain never reaches a root.
+    pub(crate) fn new(parents: Vec<Option<usize>>, excluded: &HashSet<usize>) -> Self {
+        let mut children = vec![Vec::new(); parents.len()];
+        let mut roots = Vec::new();
+
+        for (ring, parent) in parents.iter().enumerate() {
+            if excluded.contains(&ring) {
+                continue;
+            }
+            match parent {
+                Some(parent) => children[*parent].push(ring),
+                None => roots.push(ring),
+            }
+        }
+
+        let mut depths = vec![None; parents.len()];
+        let mut order = Vec::with_capacity(parents.len());
+        let mut queue: VecDeque<(usize, usize)> = roots.iter().map(|root| (*root, 0)).collect();
+
+        while let Some((ring, depth)) = queue.pop_front() {
+            depths[ring] = Some(depth);
+            order.push(ring);
+            queue.extend(children[ring].iter().map(|child| (*child, depth + 1)));
+        }
+
+        let parents = parents
+            .into_iter()
+            .enumerate()
+            .map(|(ring, parent)| parent.filter(|_| depths[ring].is_some()))
+            .collect();
+
+        CollationTree {
+            parents,
+            children,
+            depths,
+            roots,
+            order,
+        }
+    }
+
+    /// Number of input rings, including any left out of the tree.
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// The ring directly enclosing `ring`, or `None` for a top-level ring.
+    pub fn parent(&self, ring: usize) -> Option<usize> {
+        self.parents[ring]
+    }
+
+    /// The rings directly inside `ring`, in input order. For an exterior these
+    /// are its holes; for a hole they are the islands sitting in it.
+    pub fn children(&self, ring: usize) -> &[usize] {
+        &self.children[ring]
+    }
+
+    /// Number of rings enclosing `ring`, or `None` if it was left out.
+    pub fn depth(&self, ring: usize) -> Option<usize> {
+        self.depths[ring]
+    }
+
+    pub fn is_exterior(&self, ring: usize) -> bool {
+        matches!(self.depths[ring], Some(depth) if depth % 2 == 0)
+    }
+
+    pub fn is_hole(&self, ring: usize) -> bool {
+        matches!(self.depths[ring], Some(depth) if depth % 2 == 1)
+    }
+
+    /// The top-level exteriors, in input order.
+    pub fn roots(&self) -> &[usize] {
+        &self.roots
+    }
+
+    /// One entry per exterior ring, in input order, listing the exterior and
+    /// its holes.
+    pub fn polygons(&self) -> Vec<PolygonIndices> {
+        (0..self.len())
+            .filter(|ring| self.is_exterior(*ring))
+            .map(|exterior| PolygonIndices {
+                exterior,
+                interiors: self.children[exterior].clone(),
+            })
+            .collect()
+    }
+
+    /// The polygons `polygons` returns, grouped by island depth: entry 0 holds
+    /// the top-level polygons, entry 1 the polygons sitting in their holes,
+    /// and so on. Each group is in input order.
+    pub fn polygons_by_depth(&self) -> Vec<Vec<PolygonIndices>> {
+        let mut depths: Vec<Vec<PolygonIndices>> = Vec::new();
+        for polygon in self.polygons() {
+            let depth = self.depths[polygon.exterior].unwrap_or_default() / 2;
+            if depths.len() <= depth {
+                depths.resize(depth + 1, Vec::new());
+            }
+            depths[depth].push(polygon);
+        }
+        depths
+    }
+
+    /// Every ring in the tree, each before any ring it encloses, level by
+    /// level.
+    pub fn outermost_first(&self) -> impl Iterator<Item = usize> + '_ {
+        self.order.iter().copied()
+    }
+
+    /// Every ring in the tree, each before any ring enclosing it, deepest
+    /// level first.
+    pub fn innermost_first(&self) -> impl Iterator<Item = usize> + '_ {
+        self.order.iter().rev().copied()
+    }
+}